@@ -125,6 +125,16 @@ impl<T: BloomHashIndex> Bloom<T> {
         }
         true
     }
+
+    /// Estimated probability that `contains` returns true for a key which was
+    /// never added, given the fraction of bits currently set.
+    pub fn false_positive_rate(&self) -> f64 {
+        if self.bits.is_empty() {
+            return 1.0;
+        }
+        let fill_ratio = (self.num_bits_set as f64 / self.bits.len() as f64).min(1.0);
+        fill_ratio.powi(i32::try_from(self.keys.len()).unwrap_or(i32::MAX))
+    }
 }
 
 fn slice_hash(slice: &[u8], hash_index: u64) -> u64 {
@@ -303,6 +313,33 @@ mod test {
         assert_eq!(Bloom::<Hash>::num_keys(20f64, 1000f64) as u64, 1u64);
     }
 
+    #[test]
+    fn test_false_positive_rate() {
+        let mut rng = rand::thread_rng();
+        let mut bloom: Bloom<Hash> = Bloom::random(1000, 0.1, 100_000);
+        assert_eq!(bloom.false_positive_rate(), 0.0);
+        for _ in 0..1000 {
+            bloom.add(&solana_sdk::hash::new_rand(&mut rng));
+        }
+        let false_positive_rate = bloom.false_positive_rate();
+        assert!(
+            (0.05..0.2).contains(&false_positive_rate),
+            "{false_positive_rate}"
+        );
+        // Measure against keys which were never added.
+        let num_false_positives = (0..10_000)
+            .map(|_| solana_sdk::hash::new_rand(&mut rng))
+            .filter(|key| bloom.contains(key))
+            .count();
+        let measured_rate = num_false_positives as f64 / 10_000.0;
+        assert!(
+            (measured_rate - false_positive_rate).abs() < 0.05,
+            "{measured_rate} {false_positive_rate}"
+        );
+        bloom.clear();
+        assert_eq!(bloom.false_positive_rate(), 0.0);
+    }
+
     #[test]
     fn test_debug() {
         let mut b: Bloom<Hash> = Bloom::new(3, vec![100]);
@@ -75,10 +75,12 @@ source multinode-demo/common.sh --prebuild
 nodes=(
   "multinode-demo/bootstrap-validator.sh \
     --no-restart \
+    --allow-private-addr \
     --init-complete-file init-complete-node0.log \
     --dynamic-port-range 8000-8050"
   "multinode-demo/validator.sh \
     --no-restart \
+    --allow-private-addr \
     --dynamic-port-range 8050-8100
     --init-complete-file init-complete-node1.log \
     --rpc-port 18899"
@@ -91,6 +93,7 @@ if [[ extraNodes -gt 0 ]]; then
     nodes+=(
       "multinode-demo/validator.sh \
         --no-restart \
+        --allow-private-addr \
         --dynamic-port-range $portStart-$portEnd
         --label dyn$i \
         --init-complete-file init-complete-node$((1 + i)).log"
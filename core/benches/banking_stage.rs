@@ -10,7 +10,7 @@ use {
     rayon::prelude::*,
     solana_client::connection_cache::ConnectionCache,
     solana_core::{
-        banking_stage::{BankingStage, BankingStageStats},
+        banking_stage::{BankingStage, BankingStageStats, MIN_TOTAL_THREADS},
         leader_slot_banking_stage_metrics::LeaderSlotMetricsTracker,
         qos_service::QosService,
         unprocessed_packet_batches::*,
@@ -129,6 +129,32 @@ fn make_accounts_txs(txes: usize, mint_keypair: &Keypair, hash: Hash) -> Vec<Tra
         .collect()
 }
 
+fn make_contended_accounts_txs(
+    txes: usize,
+    num_hot_accounts: usize,
+    mint_keypair: &Keypair,
+    hash: Hash,
+) -> Vec<Transaction> {
+    // Every transaction writes to one of a small set of shared destination
+    // accounts, so transactions in the same batch regularly conflict on
+    // write locks and have to be retried by the banking threads.
+    let hot_accounts: Vec<_> = repeat_with(pubkey::new_rand)
+        .take(num_hot_accounts)
+        .collect();
+    let dummy = system_transaction::transfer(mint_keypair, &hot_accounts[0], 1, hash);
+    (0..txes)
+        .into_par_iter()
+        .map(|i| {
+            let mut new = dummy.clone();
+            let sig: Vec<_> = (0..64).map(|_| thread_rng().gen::<u8>()).collect();
+            new.message.account_keys[0] = pubkey::new_rand();
+            new.message.account_keys[1] = hot_accounts[i % num_hot_accounts];
+            new.signatures = vec![Signature::new(&sig[0..64])];
+            new
+        })
+        .collect()
+}
+
 fn make_programs_txs(txes: usize, hash: Hash) -> Vec<Transaction> {
     let progs = 4;
     (0..txes)
@@ -174,18 +200,24 @@ fn make_vote_txs(txes: usize) -> Vec<Transaction> {
 
 enum TransactionType {
     Accounts,
+    ContendedAccounts,
     Programs,
     AccountsAndVotes,
     ProgramsAndVotes,
 }
 
 fn bench_banking(bencher: &mut Bencher, tx_type: TransactionType) {
+    bench_banking_num_threads(bencher, tx_type, BankingStage::num_threads());
+}
+
+fn bench_banking_num_threads(bencher: &mut Bencher, tx_type: TransactionType, num_threads: u32) {
     solana_logger::setup();
-    let num_threads = BankingStage::num_threads() as usize;
     //   a multiple of packet chunk duplicates to avoid races
     const CHUNKS: usize = 8;
     const PACKETS_PER_BATCH: usize = 192;
-    let txes = PACKETS_PER_BATCH * num_threads * CHUNKS;
+    // Contended accounts share this many write-locked destinations
+    const NUM_HOT_ACCOUNTS: usize = 16;
+    let txes = PACKETS_PER_BATCH * num_threads as usize * CHUNKS;
     let mint_total = 1_000_000_000_000;
     let GenesisConfigInfo {
         mut genesis_config,
@@ -218,6 +250,12 @@ fn bench_banking(bencher: &mut Bencher, tx_type: TransactionType) {
         TransactionType::Accounts | TransactionType::AccountsAndVotes => {
             make_accounts_txs(txes, &mint_keypair, genesis_config.hash())
         }
+        TransactionType::ContendedAccounts => make_contended_accounts_txs(
+            txes,
+            NUM_HOT_ACCOUNTS,
+            &mint_keypair,
+            genesis_config.hash(),
+        ),
         TransactionType::Programs | TransactionType::ProgramsAndVotes => {
             make_programs_txs(txes, genesis_config.hash())
         }
@@ -247,11 +285,14 @@ fn bench_banking(bencher: &mut Bencher, tx_type: TransactionType) {
     });
     bank.clear_signatures();
     //sanity check, make sure all the transactions can execute in parallel
-    let res = bank.process_transactions(transactions.iter());
-    for r in res {
-        assert!(r.is_ok(), "sanity parallel execution");
+    //contended transactions are expected to hit account lock conflicts here
+    if !matches!(tx_type, TransactionType::ContendedAccounts) {
+        let res = bank.process_transactions(transactions.iter());
+        for r in res {
+            assert!(r.is_ok(), "sanity parallel execution");
+        }
+        bank.clear_signatures();
     }
-    bank.clear_signatures();
     let verified: Vec<_> = to_packet_batches(&transactions, PACKETS_PER_BATCH);
     let vote_packets = vote_txs.map(|vote_txs| {
         let mut packet_batches = to_packet_batches(&vote_txs, PACKETS_PER_BATCH);
@@ -277,12 +318,13 @@ fn bench_banking(bencher: &mut Bencher, tx_type: TransactionType) {
         );
         let cluster_info = Arc::new(cluster_info);
         let (s, _r) = unbounded();
-        let _banking_stage = BankingStage::new(
+        let _banking_stage = BankingStage::new_num_threads(
             &cluster_info,
             &poh_recorder,
             non_vote_receiver,
             tpu_vote_receiver,
             gossip_vote_receiver,
+            num_threads,
             None,
             s,
             None,
@@ -310,7 +352,7 @@ fn bench_banking(bencher: &mut Bencher, tx_type: TransactionType) {
                     .send((vote_packets[start..start + chunk_len].to_vec(), None))
                     .unwrap();
             }
-            for v in verified[start..start + chunk_len].chunks(chunk_len / num_threads) {
+            for v in verified[start..start + chunk_len].chunks(chunk_len / num_threads as usize) {
                 debug!(
                     "sending... {}..{} {} v.len: {}",
                     start,
@@ -350,6 +392,26 @@ fn bench_banking_stage_multi_accounts(bencher: &mut Bencher) {
     bench_banking(bencher, TransactionType::Accounts);
 }
 
+#[bench]
+fn bench_banking_stage_multi_accounts_min_threads(bencher: &mut Bencher) {
+    // The minimum number of non-vote threads, the serial baseline
+    bench_banking_num_threads(bencher, TransactionType::Accounts, MIN_TOTAL_THREADS);
+}
+
+#[bench]
+fn bench_banking_stage_contended_accounts(bencher: &mut Bencher) {
+    bench_banking(bencher, TransactionType::ContendedAccounts);
+}
+
+#[bench]
+fn bench_banking_stage_contended_accounts_min_threads(bencher: &mut Bencher) {
+    bench_banking_num_threads(
+        bencher,
+        TransactionType::ContendedAccounts,
+        MIN_TOTAL_THREADS,
+    );
+}
+
 #[bench]
 fn bench_banking_stage_multi_programs(bencher: &mut Bencher) {
     bench_banking(bencher, TransactionType::Programs);
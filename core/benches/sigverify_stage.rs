@@ -13,7 +13,7 @@ use {
     },
     solana_core::{
         sigverify::TransactionSigVerifier,
-        sigverify_stage::{SigVerifier, SigVerifyStage},
+        sigverify_stage::{SigVerifier, SigVerifyStage, DEFAULT_DEDUPER_MAX_AGE},
     },
     solana_measure::measure::Measure,
     solana_perf::{
@@ -149,7 +149,7 @@ fn bench_sigverify_stage(bencher: &mut Bencher) {
     let (packet_s, packet_r) = unbounded();
     let (verified_s, verified_r) = unbounded();
     let verifier = TransactionSigVerifier::new(verified_s);
-    let stage = SigVerifyStage::new(packet_r, verifier, "bench");
+    let stage = SigVerifyStage::new(packet_r, verifier, "bench", DEFAULT_DEDUPER_MAX_AGE);
 
     let use_same_tx = true;
     bencher.iter(move || {
@@ -237,7 +237,7 @@ fn bench_shrink_sigverify_stage_core(bencher: &mut Bencher, discard_factor: i32)
             SigVerifyStage::maybe_shrink_batches(&mut batches);
 
         let mut verify_time = Measure::start("sigverify_batch_time");
-        let _batches = verifier.verify_batches(batches, num_valid_packets);
+        let (_batches, _) = verifier.verify_batches(batches, num_valid_packets);
         verify_time.stop();
 
         c += 1;
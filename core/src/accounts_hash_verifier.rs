@@ -3,6 +3,11 @@
 // Each interval, publish the snapshot hash which is the full accounts state
 // hash on gossip. Monitor gossip for messages from validators in the `--known-validator`s
 // set and halt the node if a mismatch is detected.
+//
+// If the accounts package carries a hash for testing (see
+// `--accounts-db-test-hash-calculation`), also compare it against the hash
+// calculated here to catch local accounts db corruption. A mismatch panics,
+// unless the node is configured to halt cleanly instead.
 
 use {
     crossbeam_channel::{Receiver, Sender},
@@ -47,6 +52,7 @@ impl AccountsHashVerifier {
         cluster_info: &Arc<ClusterInfo>,
         known_validators: Option<HashSet<Pubkey>>,
         halt_on_known_validators_accounts_hash_mismatch: bool,
+        halt_on_accounts_hash_mismatch: bool,
         fault_injection_rate_slots: u64,
         snapshot_config: SnapshotConfig,
     ) -> Self {
@@ -79,6 +85,7 @@ impl AccountsHashVerifier {
                             &cluster_info,
                             known_validators.as_ref(),
                             halt_on_known_validators_accounts_hash_mismatch,
+                            halt_on_accounts_hash_mismatch,
                             pending_snapshot_package.as_ref(),
                             &mut hashes,
                             &exit,
@@ -180,13 +187,22 @@ impl AccountsHashVerifier {
         cluster_info: &ClusterInfo,
         known_validators: Option<&HashSet<Pubkey>>,
         halt_on_known_validator_accounts_hash_mismatch: bool,
+        halt_on_accounts_hash_mismatch: bool,
         pending_snapshot_package: Option<&PendingSnapshotPackage>,
         hashes: &mut Vec<(Slot, Hash)>,
         exit: &Arc<AtomicBool>,
         fault_injection_rate_slots: u64,
         snapshot_config: &SnapshotConfig,
     ) {
-        let accounts_hash = Self::calculate_and_verify_accounts_hash(&accounts_package);
+        let accounts_hash = match Self::calculate_and_verify_accounts_hash(
+            &accounts_package,
+            halt_on_accounts_hash_mismatch,
+            exit,
+        ) {
+            Some(accounts_hash) => accounts_hash,
+            // Never publish or snapshot a hash that is known to be bad
+            None => return,
+        };
 
         Self::save_epoch_accounts_hash(&accounts_package, accounts_hash);
 
@@ -209,8 +225,13 @@ impl AccountsHashVerifier {
         );
     }
 
-    /// returns calculated accounts hash
-    fn calculate_and_verify_accounts_hash(accounts_package: &AccountsPackage) -> AccountsHash {
+    /// returns calculated accounts hash, or None if it does not match the
+    /// package's expected hash and the validator is halting
+    fn calculate_and_verify_accounts_hash(
+        accounts_package: &AccountsPackage,
+        halt_on_accounts_hash_mismatch: bool,
+        exit: &AtomicBool,
+    ) -> Option<AccountsHash> {
         let mut measure_hash = Measure::start("hash");
         let mut sort_time = Measure::start("sort_storages");
         let sorted_storages = SortedStorages::new(&accounts_package.snapshot_storages);
@@ -282,9 +303,21 @@ impl AccountsHashVerifier {
             accounts_package.expected_capitalization, lamports,
             "accounts hash capitalization mismatch"
         );
-        if let Some(expected_hash) = accounts_package.accounts_hash_for_testing {
-            assert_eq!(expected_hash, accounts_hash);
-        };
+        if Self::is_accounts_hash_mismatch(
+            accounts_package.slot,
+            accounts_package.accounts_hash_for_testing,
+            accounts_hash,
+        ) {
+            if !halt_on_accounts_hash_mismatch {
+                panic!("accounts hash mismatch at slot {}", accounts_package.slot);
+            }
+            error!(
+                "halting the validator due to an accounts hash mismatch at slot {}",
+                accounts_package.slot
+            );
+            exit.store(true, Ordering::Relaxed);
+            return None;
+        }
 
         accounts_package
             .accounts
@@ -307,7 +340,7 @@ impl AccountsHashVerifier {
             "accounts_hash_verifier",
             ("calculate_hash", measure_hash.as_us(), i64),
         );
-        accounts_hash
+        Some(accounts_hash)
     }
 
     fn save_epoch_accounts_hash(accounts_package: &AccountsPackage, accounts_hash: AccountsHash) {
@@ -325,6 +358,37 @@ impl AccountsHashVerifier {
         }
     }
 
+    /// Returns true if `expected_accounts_hash`, calculated from the accounts
+    /// index and sent along with the accounts package for testing, differs from
+    /// the `accounts_hash` calculated from the package's storages
+    fn is_accounts_hash_mismatch(
+        slot: Slot,
+        expected_accounts_hash: Option<AccountsHash>,
+        accounts_hash: AccountsHash,
+    ) -> bool {
+        let expected_accounts_hash = match expected_accounts_hash {
+            Some(expected_accounts_hash) if expected_accounts_hash != accounts_hash => {
+                expected_accounts_hash
+            }
+            _ => return false,
+        };
+        error!(
+            "accounts hash mismatch at slot {slot}: index: {}, storages: {}",
+            expected_accounts_hash.0, accounts_hash.0,
+        );
+        datapoint_error!(
+            "accounts_hash_verifier-mismatch",
+            ("slot", slot, i64),
+            (
+                "expected_hash",
+                expected_accounts_hash.0.to_string(),
+                String
+            ),
+            ("calculated_hash", accounts_hash.0.to_string(), String),
+        );
+        true
+    }
+
     fn generate_fault_hash(original_hash: &Hash) -> Hash {
         use {
             rand::{thread_rng, Rng},
@@ -549,6 +613,7 @@ mod tests {
                 &cluster_info,
                 Some(&known_validators),
                 false,
+                false,
                 None,
                 &mut hashes,
                 &exit,
@@ -580,6 +645,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_is_accounts_hash_mismatch() {
+        let accounts_hash = AccountsHash(hash(&[1]));
+        assert!(!AccountsHashVerifier::is_accounts_hash_mismatch(
+            0,
+            None,
+            accounts_hash
+        ));
+        assert!(!AccountsHashVerifier::is_accounts_hash_mismatch(
+            0,
+            Some(accounts_hash),
+            accounts_hash
+        ));
+        assert!(AccountsHashVerifier::is_accounts_hash_mismatch(
+            0,
+            Some(AccountsHash(hash(&[2]))),
+            accounts_hash
+        ));
+    }
+
     /// Ensure that unhandled accounts packages are properly re-enqueued or dropped
     ///
     /// The accounts package handler should re-enqueue unhandled accounts packages, if those
@@ -767,7 +767,7 @@ mod test {
                 tests::{replay_blockstore_components, ReplayBlockstoreComponents},
                 ReplayStage,
             },
-            serve_repair::MAX_ANCESTOR_RESPONSES,
+            serve_repair::{DEFAULT_MAX_REPAIR_RESPONSE_BYTES_PER_SECOND, MAX_ANCESTOR_RESPONSES},
             vote_simulator::VoteSimulator,
         },
         solana_gossip::{
@@ -1016,6 +1016,7 @@ mod test {
                 blockstore,
                 requests_receiver,
                 response_sender,
+                DEFAULT_MAX_REPAIR_RESPONSE_BYTES_PER_SECOND,
                 exit.clone(),
             );
 
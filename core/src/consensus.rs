@@ -479,9 +479,8 @@ impl Tower {
         let mut new_vote = if is_direct_vote_state_update_enabled {
             let vote = Vote::new(vec![vote_slot], vote_hash);
             process_vote_unchecked(&mut self.vote_state, vote);
-            VoteTransaction::from(VoteStateUpdate::new(
-                self.vote_state.votes.clone(),
-                self.vote_state.root_slot,
+            VoteTransaction::from(VoteStateUpdate::from_vote_state(
+                &self.vote_state,
                 vote_hash,
             ))
         } else {
@@ -130,7 +130,12 @@ impl PartialOrd for ImmutableDeserializedPacket {
 
 impl Ord for ImmutableDeserializedPacket {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.priority().cmp(&other.priority())
+        // At the same compute unit price, prefer the packet requesting fewer
+        // compute units, since it takes up less of the block for the same fee
+        // rate.
+        self.priority()
+            .cmp(&other.priority())
+            .then_with(|| other.compute_unit_limit().cmp(&self.compute_unit_limit()))
     }
 }
 
@@ -3,10 +3,17 @@ use {
         cluster_info::ClusterInfo, legacy_contact_info::LegacyContactInfo as ContactInfo,
     },
     solana_poh::poh_recorder::PohRecorder,
-    solana_sdk::{clock::FORWARD_TRANSACTIONS_TO_LEADER_AT_SLOT_OFFSET, pubkey::Pubkey},
+    solana_sdk::{
+        clock::{FORWARD_TRANSACTIONS_TO_LEADER_AT_SLOT_OFFSET, NUM_CONSECUTIVE_LEADER_SLOTS},
+        pubkey::Pubkey,
+    },
     std::{net::SocketAddr, sync::RwLock},
 };
 
+/// Number of slots past the forwarding offset searched for a leader that is
+/// reachable through gossip, covering at least the next two leaders.
+const MAX_NEXT_LEADER_SEARCH_SLOTS: u64 = 2 * NUM_CONSECUTIVE_LEADER_SLOTS;
+
 pub(crate) fn next_leader_tpu(
     cluster_info: &ClusterInfo,
     poh_recorder: &RwLock<PohRecorder>,
@@ -34,17 +41,76 @@ fn next_leader_x<F>(
     port_selector: F,
 ) -> Option<(Pubkey, SocketAddr)>
 where
-    F: FnOnce(&ContactInfo) -> SocketAddr,
+    F: Fn(&ContactInfo) -> SocketAddr,
+{
+    let upcoming_leaders: Vec<_> = {
+        let poh_recorder = poh_recorder.read().unwrap();
+        (0..MAX_NEXT_LEADER_SEARCH_SLOTS)
+            .filter_map(|n| {
+                poh_recorder.leader_after_n_slots(FORWARD_TRANSACTIONS_TO_LEADER_AT_SLOT_OFFSET + n)
+            })
+            .collect()
+    };
+    first_reachable_leader(upcoming_leaders, |leader_pubkey| {
+        cluster_info.lookup_contact_info(leader_pubkey, &port_selector)
+    })
+}
+
+/// Returns the first leader, in slot order, whose address can be resolved. A leader
+/// that is missing from gossip is skipped so that forwarded packets still land on
+/// one of the following leaders instead of being dropped.
+fn first_reachable_leader<L>(
+    upcoming_leaders: impl IntoIterator<Item = Pubkey>,
+    lookup_addr: L,
+) -> Option<(Pubkey, SocketAddr)>
+where
+    L: Fn(&Pubkey) -> Option<SocketAddr>,
 {
-    let leader_pubkey = poh_recorder
-        .read()
-        .unwrap()
-        .leader_after_n_slots(FORWARD_TRANSACTIONS_TO_LEADER_AT_SLOT_OFFSET);
-    if let Some(leader_pubkey) = leader_pubkey {
-        cluster_info
-            .lookup_contact_info(&leader_pubkey, port_selector)
-            .map(|addr| (leader_pubkey, addr))
-    } else {
-        None
+    let mut last_leader = None;
+    upcoming_leaders
+        .into_iter()
+        // Leaders hold consecutive slots, only look each of them up once
+        .filter(|leader_pubkey| last_leader.replace(*leader_pubkey) != Some(*leader_pubkey))
+        .find_map(|leader_pubkey| lookup_addr(&leader_pubkey).map(|addr| (leader_pubkey, addr)))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        std::{cell::Cell, collections::HashMap},
+    };
+
+    #[test]
+    fn test_first_reachable_leader() {
+        let leader1 = Pubkey::new_unique();
+        let leader2 = Pubkey::new_unique();
+        let leader3 = Pubkey::new_unique();
+        let addr2 = SocketAddr::from(([127, 0, 0, 2], 8002));
+        let addr3 = SocketAddr::from(([127, 0, 0, 3], 8003));
+        let addrs = HashMap::from([(leader2, addr2), (leader3, addr3)]);
+        let num_lookups = Cell::new(0);
+        let lookup_addr = |leader_pubkey: &Pubkey| {
+            num_lookups.set(num_lookups.get() + 1);
+            addrs.get(leader_pubkey).copied()
+        };
+
+        // The first upcoming leader isn't in gossip, so fall back to the next one
+        let upcoming_leaders = vec![leader1, leader1, leader2, leader2, leader3];
+        assert_eq!(
+            first_reachable_leader(upcoming_leaders, lookup_addr),
+            Some((leader2, addr2))
+        );
+        assert_eq!(num_lookups.get(), 2);
+
+        assert_eq!(
+            first_reachable_leader(vec![leader3, leader2], lookup_addr),
+            Some((leader3, addr3))
+        );
+        assert_eq!(
+            first_reachable_leader(vec![leader1, leader1], lookup_addr),
+            None
+        );
+        assert_eq!(first_reachable_leader(Vec::new(), lookup_addr), None);
     }
 }
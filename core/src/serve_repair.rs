@@ -65,6 +65,8 @@ pub const MAX_ORPHAN_REPAIR_RESPONSES: usize = 11;
 pub(crate) const REPAIR_PEERS_CACHE_CAPACITY: usize = 128;
 // Limit cache entries ttl in order to avoid re-using outdated data.
 const REPAIR_PEERS_CACHE_TTL: Duration = Duration::from_secs(10);
+/// Default limit on the bandwidth used to serve repair responses
+pub const DEFAULT_MAX_REPAIR_RESPONSE_BYTES_PER_SECOND: usize = 12_000_000;
 pub const MAX_ANCESTOR_BYTES_IN_PACKET: usize =
     PACKET_DATA_SIZE -
     SIZE_OF_NONCE -
@@ -659,11 +661,12 @@ impl ServeRepair {
         blockstore: Arc<Blockstore>,
         requests_receiver: PacketBatchReceiver,
         response_sender: PacketBatchSender,
+        max_response_bytes_per_second: usize,
         exit: Arc<AtomicBool>,
     ) -> JoinHandle<()> {
         const INTERVAL_MS: u64 = 1000;
-        const MAX_BYTES_PER_SECOND: usize = 12_000_000;
-        const MAX_BYTES_PER_INTERVAL: usize = MAX_BYTES_PER_SECOND * INTERVAL_MS as usize / 1000;
+        let max_bytes_per_interval =
+            max_response_bytes_per_second.saturating_mul(INTERVAL_MS as usize) / 1000;
 
         // rate limit delay should be greater than the repair request iteration delay
         assert!(REPAIR_PING_CACHE_RATE_LIMIT_DELAY > Duration::from_millis(REPAIR_MS));
@@ -702,7 +705,7 @@ impl ServeRepair {
                         self.report_reset_stats(&mut stats);
                         last_print = Instant::now();
                     }
-                    data_budget.update(INTERVAL_MS, |_bytes| MAX_BYTES_PER_INTERVAL);
+                    data_budget.update(INTERVAL_MS, |_bytes| max_bytes_per_interval);
                 }
             })
             .unwrap()
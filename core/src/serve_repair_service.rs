@@ -25,6 +25,7 @@ impl ServeRepairService {
         serve_repair_socket: UdpSocket,
         socket_addr_space: SocketAddrSpace,
         stats_reporter_sender: Sender<Box<dyn FnOnce() + Send>>,
+        max_response_bytes_per_second: usize,
         exit: Arc<AtomicBool>,
     ) -> Self {
         let (request_sender, request_receiver) = unbounded();
@@ -52,7 +53,13 @@ impl ServeRepairService {
             socket_addr_space,
             Some(stats_reporter_sender),
         );
-        let t_listen = serve_repair.listen(blockstore, request_receiver, response_sender, exit);
+        let t_listen = serve_repair.listen(
+            blockstore,
+            request_receiver,
+            response_sender,
+            max_response_bytes_per_second,
+            exit,
+        );
 
         let thread_hdls = vec![t_receiver, t_responder, t_listen];
         Self { thread_hdls }
@@ -137,14 +137,14 @@ impl SigVerifier for TransactionSigVerifier {
         &self,
         mut batches: Vec<PacketBatch>,
         valid_packets: usize,
-    ) -> Vec<PacketBatch> {
-        sigverify::ed25519_verify(
+    ) -> (Vec<PacketBatch>, bool) {
+        let verified_on_gpu = sigverify::ed25519_verify(
             &mut batches,
             &self.recycler,
             &self.recycler_out,
             self.reject_non_vote,
             valid_packets,
         );
-        batches
+        (batches, verified_on_gpu)
     }
 }
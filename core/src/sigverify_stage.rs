@@ -35,6 +35,10 @@ const MAX_DEDUP_BATCH: usize = 165_000;
 // 50ms/(25us/packet) = 2000 packets
 const MAX_SIGVERIFY_BATCH: usize = 2_000;
 
+// Deduper filter is cleared at least this often, so a packet is only dropped as a
+// duplicate if an identical packet was received within roughly this interval
+pub const DEFAULT_DEDUPER_MAX_AGE: Duration = Duration::from_secs(2);
+
 // Packet batch shrinker will reorganize packets into compacted batches if 10%
 // or more of the packets in a group of packet batches have been discarded.
 const MAX_DISCARDED_PACKET_RATE: f64 = 0.10;
@@ -56,7 +60,12 @@ pub struct SigVerifyStage {
 
 pub trait SigVerifier {
     type SendType: std::fmt::Debug;
-    fn verify_batches(&self, batches: Vec<PacketBatch>, valid_packets: usize) -> Vec<PacketBatch>;
+    /// Returns the verified batches, and true if they were verified on the GPU
+    fn verify_batches(
+        &self,
+        batches: Vec<PacketBatch>,
+        valid_packets: usize,
+    ) -> (Vec<PacketBatch>, bool);
     fn process_received_packet(
         &mut self,
         _packet: &mut Packet,
@@ -78,6 +87,8 @@ struct SigVerifierStats {
     verify_batches_pp_us_hist: histogram::Histogram, // per-packet time to call verify_batch
     discard_packets_pp_us_hist: histogram::Histogram, // per-packet time to call verify_batch
     dedup_packets_pp_us_hist: histogram::Histogram, // per-packet time to call verify_batch
+    gpu_verify_batches_us_hist: histogram::Histogram, // time to call verify_batch on the GPU
+    cpu_verify_batches_us_hist: histogram::Histogram, // time to call verify_batch on the CPU
     batches_hist: histogram::Histogram,         // number of packet batches per verify call
     packets_hist: histogram::Histogram,         // number of packets per verify call
     total_batches: usize,
@@ -92,6 +103,8 @@ struct SigVerifierStats {
     total_discard_random_time_us: usize,
     total_verify_time_us: usize,
     total_shrink_time_us: usize,
+    total_gpu_verify_calls: usize,
+    total_cpu_verify_calls: usize,
 }
 
 impl SigVerifierStats {
@@ -180,6 +193,40 @@ impl SigVerifierStats {
                 self.dedup_packets_pp_us_hist.mean().unwrap_or(0),
                 i64
             ),
+            (
+                "gpu_verify_batches_us_90pct",
+                self.gpu_verify_batches_us_hist
+                    .percentile(90.0)
+                    .unwrap_or(0),
+                i64
+            ),
+            (
+                "gpu_verify_batches_us_max",
+                self.gpu_verify_batches_us_hist.maximum().unwrap_or(0),
+                i64
+            ),
+            (
+                "gpu_verify_batches_us_mean",
+                self.gpu_verify_batches_us_hist.mean().unwrap_or(0),
+                i64
+            ),
+            (
+                "cpu_verify_batches_us_90pct",
+                self.cpu_verify_batches_us_hist
+                    .percentile(90.0)
+                    .unwrap_or(0),
+                i64
+            ),
+            (
+                "cpu_verify_batches_us_max",
+                self.cpu_verify_batches_us_hist.maximum().unwrap_or(0),
+                i64
+            ),
+            (
+                "cpu_verify_batches_us_mean",
+                self.cpu_verify_batches_us_hist.mean().unwrap_or(0),
+                i64
+            ),
             (
                 "batches_90pct",
                 self.batches_hist.percentile(90.0).unwrap_or(0),
@@ -212,6 +259,8 @@ impl SigVerifierStats {
             ),
             ("total_verify_time_us", self.total_verify_time_us, i64),
             ("total_shrink_time_us", self.total_shrink_time_us, i64),
+            ("total_gpu_verify_calls", self.total_gpu_verify_calls, i64),
+            ("total_cpu_verify_calls", self.total_cpu_verify_calls, i64),
         );
     }
 }
@@ -222,9 +271,9 @@ impl SigVerifier for DisabledSigVerifier {
         &self,
         mut batches: Vec<PacketBatch>,
         _valid_packets: usize,
-    ) -> Vec<PacketBatch> {
+    ) -> (Vec<PacketBatch>, bool) {
         sigverify::ed25519_verify_disabled(&mut batches);
-        batches
+        (batches, false)
     }
 
     fn send_packets(&mut self, _packet_batches: Vec<PacketBatch>) -> Result<(), Self::SendType> {
@@ -238,8 +287,9 @@ impl SigVerifyStage {
         packet_receiver: find_packet_sender_stake_stage::FindPacketSenderStakeReceiver,
         verifier: T,
         name: &'static str,
+        dedup_max_age: Duration,
     ) -> Self {
-        let thread_hdl = Self::verifier_services(packet_receiver, verifier, name);
+        let thread_hdl = Self::verifier_services(packet_receiver, verifier, name, dedup_max_age);
         Self { thread_hdl }
     }
 
@@ -346,7 +396,8 @@ impl SigVerifyStage {
         let (pre_shrink_time_us, pre_shrink_total) = Self::maybe_shrink_batches(&mut batches);
 
         let mut verify_time = Measure::start("sigverify_batch_time");
-        let mut batches = verifier.verify_batches(batches, num_packets_to_verify);
+        let (mut batches, verified_on_gpu) =
+            verifier.verify_batches(batches, num_packets_to_verify);
         let num_valid_packets = count_valid_packets(
             &batches,
             #[inline(always)]
@@ -384,6 +435,19 @@ impl SigVerifyStage {
             .dedup_packets_pp_us_hist
             .increment(dedup_time.as_us() / (num_packets as u64))
             .unwrap();
+        if verified_on_gpu {
+            stats
+                .gpu_verify_batches_us_hist
+                .increment(verify_time.as_us())
+                .unwrap();
+            stats.total_gpu_verify_calls += 1;
+        } else {
+            stats
+                .cpu_verify_batches_us_hist
+                .increment(verify_time.as_us())
+                .unwrap();
+            stats.total_cpu_verify_calls += 1;
+        }
         stats.batches_hist.increment(batches_len as u64).unwrap();
         stats.packets_hist.increment(num_packets as u64).unwrap();
         stats.total_batches += batches_len;
@@ -406,15 +470,15 @@ impl SigVerifyStage {
         packet_receiver: find_packet_sender_stake_stage::FindPacketSenderStakeReceiver,
         mut verifier: T,
         name: &'static str,
+        dedup_max_age: Duration,
     ) -> JoinHandle<()> {
         let mut stats = SigVerifierStats::default();
         let mut last_print = Instant::now();
-        const MAX_DEDUPER_AGE: Duration = Duration::from_secs(2);
         const MAX_DEDUPER_ITEMS: u32 = 1_000_000;
         Builder::new()
             .name("solSigVerifier".to_string())
             .spawn(move || {
-                let mut deduper = Deduper::new(MAX_DEDUPER_ITEMS, MAX_DEDUPER_AGE);
+                let mut deduper = Deduper::new(MAX_DEDUPER_ITEMS, dedup_max_age);
                 loop {
                     deduper.reset();
                     if let Err(e) =
@@ -447,8 +511,9 @@ impl SigVerifyStage {
         packet_receiver: find_packet_sender_stake_stage::FindPacketSenderStakeReceiver,
         verifier: T,
         name: &'static str,
+        dedup_max_age: Duration,
     ) -> JoinHandle<()> {
-        Self::verifier_service(packet_receiver, verifier, name)
+        Self::verifier_service(packet_receiver, verifier, name, dedup_max_age)
     }
 
     pub fn join(self) -> thread::Result<()> {
@@ -534,7 +599,7 @@ mod tests {
         let (packet_s, packet_r) = unbounded();
         let (verified_s, verified_r) = unbounded();
         let verifier = TransactionSigVerifier::new(verified_s);
-        let stage = SigVerifyStage::new(packet_r, verifier, "test");
+        let stage = SigVerifyStage::new(packet_r, verifier, "test", DEFAULT_DEDUPER_MAX_AGE);
 
         let use_same_tx = true;
         let now = Instant::now();
@@ -418,4 +418,37 @@ pub mod test {
         assert_eq!(loaded.vote_state.root_slot, Some(1));
         assert_eq!(loaded.stray_restored_slot(), None);
     }
+
+    #[test]
+    fn test_tower_store_load_preserves_lockouts() {
+        let tower_path = TempDir::new().unwrap();
+        let identity_keypair = Keypair::new();
+        let node_pubkey = identity_keypair.pubkey();
+        let tower_storage = FileTowerStorage::new(tower_path.path().to_path_buf());
+
+        let mut tower = Tower::new_for_tests(10, 0.9);
+        tower.node_pubkey = node_pubkey;
+        for slot in 1..=4 {
+            tower.record_vote(slot, Hash::default());
+        }
+        tower.save(&tower_storage, &identity_keypair).unwrap();
+
+        // Voting on a fork that doesn't descend from the last vote is still
+        // locked out after a restart
+        let loaded = Tower::restore(&tower_storage, &node_pubkey).unwrap();
+        assert_eq!(loaded.tower_slots(), tower.tower_slots());
+        let fork_ancestors = [0, 1, 2].into_iter().collect();
+        assert!(tower.is_locked_out(5, &fork_ancestors));
+        assert!(loaded.is_locked_out(5, &fork_ancestors));
+
+        // Storing again overwrites the previous tower
+        tower.record_vote(5, Hash::default());
+        tower.save(&tower_storage, &identity_keypair).unwrap();
+        let loaded = Tower::restore(&tower_storage, &node_pubkey).unwrap();
+        assert_eq!(loaded.tower_slots(), vec![1, 2, 3, 4, 5]);
+        assert!(!tower_storage
+            .filename(&node_pubkey)
+            .with_extension("bin.new")
+            .exists());
+    }
 }
@@ -12,7 +12,7 @@ use {
         fetch_stage::FetchStage,
         find_packet_sender_stake_stage::FindPacketSenderStakeStage,
         sigverify::TransactionSigVerifier,
-        sigverify_stage::SigVerifyStage,
+        sigverify_stage::{SigVerifyStage, DEFAULT_DEDUPER_MAX_AGE},
         staked_nodes_updater_service::StakedNodesUpdaterService,
     },
     crossbeam_channel::{unbounded, Receiver},
@@ -39,11 +39,14 @@ use {
         net::UdpSocket,
         sync::{atomic::AtomicBool, Arc, RwLock},
         thread,
+        time::Duration,
     },
 };
 
 pub const DEFAULT_TPU_COALESCE_MS: u64 = 5;
 
+pub const DEFAULT_TPU_DEDUP_RESET_MS: u64 = DEFAULT_DEDUPER_MAX_AGE.as_millis() as u64;
+
 // allow multiple connections for NAT and any open/close overlap
 pub const MAX_QUIC_CONNECTIONS_PER_PEER: usize = 8;
 
@@ -92,6 +95,7 @@ impl Tpu {
         replay_vote_sender: ReplayVoteSender,
         bank_notification_sender: Option<BankNotificationSender>,
         tpu_coalesce_ms: u64,
+        tpu_dedup_reset_ms: u64,
         cluster_confirmed_slot_sender: GossipDuplicateConfirmedSlotsSender,
         connection_cache: &Arc<ConnectionCache>,
         keypair: &Keypair,
@@ -187,9 +191,15 @@ impl Tpu {
         )
         .unwrap();
 
+        let dedup_max_age = Duration::from_millis(tpu_dedup_reset_ms);
         let sigverify_stage = {
             let verifier = TransactionSigVerifier::new(non_vote_sender);
-            SigVerifyStage::new(find_packet_sender_stake_receiver, verifier, "tpu-verifier")
+            SigVerifyStage::new(
+                find_packet_sender_stake_receiver,
+                verifier,
+                "tpu-verifier",
+                dedup_max_age,
+            )
         };
 
         let (tpu_vote_sender, tpu_vote_receiver) = unbounded();
@@ -200,6 +210,7 @@ impl Tpu {
                 vote_find_packet_sender_stake_receiver,
                 verifier,
                 "tpu-vote-verifier",
+                dedup_max_age,
             )
         };
 
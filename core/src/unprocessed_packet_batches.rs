@@ -64,9 +64,7 @@ impl PartialOrd for DeserializedPacket {
 
 impl Ord for DeserializedPacket {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.immutable_section()
-            .priority()
-            .cmp(&other.immutable_section().priority())
+        self.immutable_section().cmp(other.immutable_section())
     }
 }
 
@@ -415,6 +413,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unprocessed_packet_batches_compute_unit_limit_tie_break() {
+        // Same compute unit price, the smaller request is preferred
+        let small_packet = packet_with_priority_details(10, 200_000);
+        let large_packet = packet_with_priority_details(10, 400_000);
+        let higher_price_packet = packet_with_priority_details(11, 400_000);
+        assert!(small_packet > large_packet);
+        assert!(higher_price_packet > small_packet);
+
+        let mut unprocessed_packet_batches = UnprocessedPacketBatches::with_capacity(1);
+        unprocessed_packet_batches.push(small_packet.clone());
+        assert_eq!(
+            unprocessed_packet_batches
+                .push(large_packet.clone())
+                .unwrap(),
+            large_packet
+        );
+        assert_eq!(unprocessed_packet_batches.pop_max().unwrap(), small_packet);
+    }
+
     #[test]
     fn test_unprocessed_packet_batches_pop_max_n() {
         let num_packets = 10;
@@ -13,7 +13,7 @@ use {
         poh_timing_report_service::PohTimingReportService,
         rewards_recorder_service::{RewardsRecorderSender, RewardsRecorderService},
         sample_performance_service::SamplePerformanceService,
-        serve_repair::ServeRepair,
+        serve_repair::{ServeRepair, DEFAULT_MAX_REPAIR_RESPONSE_BYTES_PER_SECOND},
         serve_repair_service::ServeRepairService,
         sigverify,
         snapshot_packager_service::SnapshotPackagerService,
@@ -22,7 +22,7 @@ use {
             verify_net_stats_access, SystemMonitorService, SystemMonitorStatsReportConfig,
         },
         tower_storage::TowerStorage,
-        tpu::{Tpu, TpuSockets, DEFAULT_TPU_COALESCE_MS},
+        tpu::{Tpu, TpuSockets, DEFAULT_TPU_COALESCE_MS, DEFAULT_TPU_DEDUP_RESET_MS},
         tvu::{Tvu, TvuConfig, TvuSockets},
     },
     crossbeam_channel::{bounded, unbounded, Receiver},
@@ -52,7 +52,7 @@ use {
     solana_measure::measure::Measure,
     solana_metrics::{datapoint_info, poh_timing_point::PohTimingSender},
     solana_poh::{
-        poh_recorder::PohRecorder,
+        poh_recorder::{PohRecorder, MAX_GRACE_SLOTS},
         poh_service::{self, PohService},
     },
     solana_rpc::{
@@ -141,8 +141,10 @@ pub struct ValidatorConfig {
     pub known_validators: Option<HashSet<Pubkey>>, // None = trust all
     pub repair_validators: Option<HashSet<Pubkey>>, // None = repair from all
     pub repair_whitelist: Arc<RwLock<HashSet<Pubkey>>>, // Empty = repair with all
+    pub max_repair_response_bytes_per_second: usize,
     pub gossip_validators: Option<HashSet<Pubkey>>, // None = gossip with all
     pub halt_on_known_validators_accounts_hash_mismatch: bool,
+    pub halt_on_accounts_hash_mismatch: bool,
     pub accounts_hash_fault_injection_slots: u64, // 0 = no fault injection
     pub accounts_hash_interval_slots: u64,
     pub max_genesis_archive_unpacked_size: u64,
@@ -161,6 +163,7 @@ pub struct ValidatorConfig {
     pub no_os_disk_stats_reporting: bool,
     pub poh_pinned_cpu_core: usize,
     pub poh_hashes_per_batch: u64,
+    pub max_leader_grace_slots: u64,
     pub process_ledger_before_services: bool,
     pub account_indexes: AccountSecondaryIndexes,
     pub accounts_db_config: Option<AccountsDbConfig>,
@@ -168,6 +171,7 @@ pub struct ValidatorConfig {
     pub accounts_db_test_hash_calculation: bool,
     pub accounts_db_skip_shrink: bool,
     pub tpu_coalesce_ms: u64,
+    pub tpu_dedup_reset_ms: u64,
     pub staked_nodes_overrides: Arc<RwLock<HashMap<Pubkey, u64>>>,
     pub validator_exit: Arc<RwLock<Exit>>,
     pub no_wait_for_vote_to_start_leader: bool,
@@ -203,8 +207,10 @@ impl Default for ValidatorConfig {
             known_validators: None,
             repair_validators: None,
             repair_whitelist: Arc::new(RwLock::new(HashSet::default())),
+            max_repair_response_bytes_per_second: DEFAULT_MAX_REPAIR_RESPONSE_BYTES_PER_SECOND,
             gossip_validators: None,
             halt_on_known_validators_accounts_hash_mismatch: false,
+            halt_on_accounts_hash_mismatch: false,
             accounts_hash_fault_injection_slots: 0,
             accounts_hash_interval_slots: std::u64::MAX,
             max_genesis_archive_unpacked_size: MAX_GENESIS_ARCHIVE_UNPACKED_SIZE,
@@ -223,12 +229,14 @@ impl Default for ValidatorConfig {
             no_os_disk_stats_reporting: true,
             poh_pinned_cpu_core: poh_service::DEFAULT_PINNED_CPU_CORE,
             poh_hashes_per_batch: poh_service::DEFAULT_HASHES_PER_BATCH,
+            max_leader_grace_slots: MAX_GRACE_SLOTS,
             process_ledger_before_services: false,
             account_indexes: AccountSecondaryIndexes::default(),
             warp_slot: None,
             accounts_db_test_hash_calculation: false,
             accounts_db_skip_shrink: false,
             tpu_coalesce_ms: DEFAULT_TPU_COALESCE_MS,
+            tpu_dedup_reset_ms: DEFAULT_TPU_DEDUP_RESET_MS,
             staked_nodes_overrides: Arc::new(RwLock::new(HashMap::new())),
             validator_exit: Arc::new(RwLock::new(Exit::default())),
             no_wait_for_vote_to_start_leader: true,
@@ -613,6 +621,7 @@ impl Validator {
             &cluster_info,
             config.known_validators.clone(),
             config.halt_on_known_validators_accounts_hash_mismatch,
+            config.halt_on_accounts_hash_mismatch,
             config.accounts_hash_fault_injection_slots,
             config.snapshot_config.clone(),
         );
@@ -733,6 +742,7 @@ impl Validator {
                 &leader_schedule_cache,
                 &genesis_config.poh_config,
                 Some(poh_timing_point_sender),
+                config.max_leader_grace_slots,
                 exit.clone(),
             )
         };
@@ -883,6 +893,7 @@ impl Validator {
             node.sockets.serve_repair,
             socket_addr_space,
             stats_reporter_sender,
+            config.max_repair_response_bytes_per_second,
             exit.clone(),
         );
 
@@ -1010,6 +1021,7 @@ impl Validator {
             replay_vote_sender,
             bank_notification_sender,
             config.tpu_coalesce_ms,
+            config.tpu_dedup_reset_ms,
             cluster_confirmed_slot_sender,
             &connection_cache,
             &identity_keypair,
@@ -200,6 +200,7 @@ impl BackgroundServices {
             &cluster_info,
             None,
             false,
+            false,
             0,
             snapshot_config.clone(),
         );
@@ -230,6 +230,7 @@ fn run_bank_forks_snapshot_n<F>(
         &cluster_info,
         None,
         false,
+        false,
         0,
         snapshot_test_config.snapshot_config.clone(),
     );
@@ -744,6 +745,7 @@ fn test_bank_forks_incremental_snapshot(
         &cluster_info,
         None,
         false,
+        false,
         0,
         snapshot_test_config.snapshot_config.clone(),
     );
@@ -1037,6 +1039,7 @@ fn test_snapshots_with_background_services(
         &cluster_info,
         None,
         false,
+        false,
         0,
         snapshot_test_config.snapshot_config.clone(),
     );
@@ -259,6 +259,7 @@ pub struct EntryVerificationState {
     verification_status: EntryVerificationStatus,
     poh_duration_us: u64,
     device_verification_data: DeviceVerificationData,
+    first_invalid_entry: Option<usize>,
 }
 
 pub struct GpuSigVerificationData {
@@ -332,6 +333,13 @@ impl EntryVerificationState {
         self.poh_duration_us
     }
 
+    /// Index of the first entry whose hash does not follow from the previous
+    /// entry's hash, if verification failed. Not known while the status is
+    /// still `Pending`.
+    pub fn first_invalid_entry(&self) -> Option<usize> {
+        self.first_invalid_entry
+    }
+
     pub fn finish_verify(&mut self) -> bool {
         match &mut self.device_verification_data {
             DeviceVerificationData::Gpu(verification_state) => {
@@ -343,12 +351,12 @@ impl EntryVerificationState {
                     .expect("unwrap Arc")
                     .into_inner()
                     .expect("into_inner");
-                let res = PAR_THREAD_POOL.install(|| {
+                self.first_invalid_entry = PAR_THREAD_POOL.install(|| {
                     hashes
                         .into_par_iter()
                         .cloned()
                         .zip(verification_state.verifications.take().unwrap())
-                        .all(|(hash, (action, expected))| {
+                        .position_first(|(hash, (action, expected))| {
                             let actual = match action {
                                 VerifyAction::Mixin(mixin) => {
                                     Poh::new(hash, None).record(mixin).unwrap().hash
@@ -356,9 +364,10 @@ impl EntryVerificationState {
                                 VerifyAction::Tick => Poh::new(hash, None).tick().unwrap().hash,
                                 VerifyAction::None => hash,
                             };
-                            actual == expected
+                            actual != expected
                         })
                 });
+                let res = self.first_invalid_entry.is_none();
                 verify_check_time.stop();
                 self.poh_duration_us += gpu_time_us + verify_check_time.as_us();
 
@@ -602,8 +611,8 @@ impl EntrySlice for [Entry] {
             transactions: vec![],
         }];
         let entry_pairs = genesis.par_iter().chain(self).zip(self);
-        let res = PAR_THREAD_POOL.install(|| {
-            entry_pairs.all(|(x0, x1)| {
+        let first_invalid_entry = PAR_THREAD_POOL.install(|| {
+            entry_pairs.position_first(|(x0, x1)| {
                 let r = x1.verify(&x0.hash);
                 if !r {
                     warn!(
@@ -613,18 +622,19 @@ impl EntrySlice for [Entry] {
                         x1.transactions.len()
                     );
                 }
-                r
+                !r
             })
         });
         let poh_duration_us = timing::duration_as_us(&now.elapsed());
         EntryVerificationState {
-            verification_status: if res {
+            verification_status: if first_invalid_entry.is_none() {
                 EntryVerificationStatus::Success
             } else {
                 EntryVerificationStatus::Failure
             },
             poh_duration_us,
             device_verification_data: DeviceVerificationData::Cpu(),
+            first_invalid_entry,
         }
     }
 
@@ -659,12 +669,12 @@ impl EntrySlice for [Entry] {
         num_hashes.resize(aligned_len, 0);
         let num_hashes: Vec<_> = num_hashes.chunks(simd_len).collect();
 
-        let res = PAR_THREAD_POOL.install(|| {
+        let first_invalid_entry = PAR_THREAD_POOL.install(|| {
             hashes_chunked
                 .par_iter_mut()
                 .zip(num_hashes)
                 .enumerate()
-                .all(|(i, (chunk, num_hashes))| {
+                .find_map_first(|(i, (chunk, num_hashes))| {
                     match simd_len {
                         8 => unsafe {
                             (api().unwrap().poh_verify_many_simd_avx2)(
@@ -689,23 +699,25 @@ impl EntrySlice for [Entry] {
                     self[entry_start..entry_end]
                         .iter()
                         .enumerate()
-                        .all(|(j, ref_entry)| {
+                        .position(|(j, ref_entry)| {
                             let start = j * HASH_BYTES;
                             let end = start + HASH_BYTES;
                             let hash = Hash::new(&chunk[start..end]);
-                            compare_hashes(hash, ref_entry)
+                            !compare_hashes(hash, ref_entry)
                         })
+                        .map(|j| entry_start + j)
                 })
         });
         let poh_duration_us = timing::duration_as_us(&now.elapsed());
         EntryVerificationState {
-            verification_status: if res {
+            verification_status: if first_invalid_entry.is_none() {
                 EntryVerificationStatus::Success
             } else {
                 EntryVerificationStatus::Failure
             },
             poh_duration_us,
             device_verification_data: DeviceVerificationData::Cpu(),
+            first_invalid_entry,
         }
     }
 
@@ -822,6 +834,7 @@ impl EntrySlice for [Entry] {
             verification_status: EntryVerificationStatus::Pending,
             poh_duration_us: timing::duration_as_us(&start.elapsed()),
             device_verification_data,
+            first_invalid_entry: None,
         }
     }
 
@@ -1128,6 +1141,35 @@ mod tests {
         assert!(!bad_ticks.verify(&zero)); // inductive step, bad
     }
 
+    #[test]
+    fn test_first_invalid_entry() {
+        solana_logger::setup();
+        let zero = Hash::default();
+        let one = hash(zero.as_ref());
+        let first_invalid_entry = |entries: &[Entry], start_hash: &Hash| {
+            let generic = entries.verify_cpu_generic(start_hash).first_invalid_entry();
+            let mut state = entries.start_verify(start_hash, VerifyRecyclers::default());
+            assert_eq!(state.finish_verify(), generic.is_none());
+            assert_eq!(state.first_invalid_entry(), generic);
+            generic
+        };
+        assert_eq!(first_invalid_entry(&[], &zero), None);
+
+        let entries = create_ticks(8, 1, zero);
+        assert_eq!(first_invalid_entry(&entries, &zero), None);
+        assert_eq!(first_invalid_entry(&entries, &one), Some(0));
+
+        // Corrupting an entry also breaks the chain for the entry after it,
+        // but only the first failure is reported.
+        let mut bad_entries = entries.clone();
+        bad_entries[5].hash = one;
+        assert_eq!(first_invalid_entry(&bad_entries, &zero), Some(5));
+        let mut bad_entries = entries;
+        bad_entries[7].hash = one;
+        bad_entries[2].num_hashes = 2;
+        assert_eq!(first_invalid_entry(&bad_entries, &zero), Some(2));
+    }
+
     #[test]
     fn test_verify_slice_with_hashes1() {
         solana_logger::setup();
@@ -118,14 +118,26 @@ pub fn compute_hash_time_ns(hashes_sample_size: u64) -> u64 {
 }
 
 pub fn compute_hashes_per_tick(duration: Duration, hashes_sample_size: u64) -> u64 {
-    let elapsed = compute_hash_time_ns(hashes_sample_size) / (1000 * 1000);
-    duration.as_millis() as u64 * hashes_sample_size / elapsed
+    let elapsed_ns = compute_hash_time_ns(hashes_sample_size);
+    hashes_per_tick_from_hash_time(duration, hashes_sample_size, elapsed_ns)
+}
+
+/// Number of hashes that fit in a tick of `duration`, given that
+/// `hashes_sample_size` hashes took `elapsed_ns` to compute
+fn hashes_per_tick_from_hash_time(
+    duration: Duration,
+    hashes_sample_size: u64,
+    elapsed_ns: u64,
+) -> u64 {
+    let hashes_per_tick =
+        duration.as_nanos() * u128::from(hashes_sample_size) / u128::from(elapsed_ns.max(1));
+    u64::try_from(hashes_per_tick).unwrap_or(u64::MAX).max(1)
 }
 
 #[cfg(test)]
 mod tests {
     use {
-        crate::poh::{Poh, PohEntry},
+        crate::poh::{hashes_per_tick_from_hash_time, Poh, PohEntry},
         matches::assert_matches,
         solana_sdk::hash::{hash, hashv, Hash},
         std::time::Duration,
@@ -188,6 +200,23 @@ mod tests {
         poh.target_poh_time(target_ns_per_tick);
     }
 
+    #[test]
+    fn test_hashes_per_tick_from_hash_time() {
+        let tick = Duration::from_micros(6_250);
+        // 1M hashes in 250ms
+        assert_eq!(
+            hashes_per_tick_from_hash_time(tick, 1_000_000, 250_000_000),
+            25_000
+        );
+        // Sub-millisecond samples don't lose precision
+        assert_eq!(hashes_per_tick_from_hash_time(tick, 1_000, 400_000), 15_625);
+        assert_eq!(
+            hashes_per_tick_from_hash_time(tick, 1_000, 0),
+            6_250_000_000
+        );
+        assert_eq!(hashes_per_tick_from_hash_time(tick, 1, u64::MAX), 1);
+    }
+
     #[test]
     fn test_poh_verify() {
         let zero = Hash::default();
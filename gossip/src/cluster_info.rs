@@ -61,7 +61,7 @@ use {
     solana_sdk::{
         clock::{Slot, DEFAULT_MS_PER_SLOT, DEFAULT_SLOTS_PER_EPOCH},
         feature_set::FeatureSet,
-        hash::Hash,
+        hash::{hash, Hash},
         pubkey::Pubkey,
         quic::QUIC_PORT_OFFSET,
         sanitize::{Sanitize, SanitizeError},
@@ -400,6 +400,76 @@ fn retain_staked(values: &mut Vec<CrdsValue>, stakes: &HashMap<Pubkey, u64>) {
     })
 }
 
+// Returns false for loopback, private, link-local, multicast, broadcast and
+// documentation ips. IPv4-mapped IPv6 addresses are checked as the IPv4
+// address they map to.
+// TODO: remove these once IpAddr::is_global is stable.
+fn is_routable_ip(ip: IpAddr) -> bool {
+    let ip = match ip {
+        IpAddr::V6(ip) => ip.to_ipv4_mapped().map(IpAddr::V4),
+        IpAddr::V4(_) => None,
+    }
+    .unwrap_or(ip);
+    match ip {
+        IpAddr::V4(ip) => {
+            !(ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_multicast()
+                || ip.is_broadcast()
+                || ip.is_documentation())
+        }
+        IpAddr::V6(ip) => {
+            let segment = ip.segments()[0];
+            // Unique local (fc00::/7) and unicast link-local (fe80::/10).
+            let is_unique_local = segment & 0xfe00 == 0xfc00;
+            let is_unicast_link_local = segment & 0xffc0 == 0xfe80;
+            !(ip.is_loopback() || is_unique_local || is_unicast_link_local || ip.is_multicast())
+        }
+    }
+}
+
+// Returns false if the value is a contact-info advertising a socket which
+// other nodes send traffic to unprompted (gossip, tvu, tvu_forwards, tpu,
+// tpu_forwards, tpu_vote or serve_repair), and which is not valid in the
+// given socket address space or, in the global address space, is not routable
+// (e.g. loopback, private or multicast ips). Unset (unspecified ip) sockets are
+// permitted, so that spy nodes and nodes not serving some of these are
+// retained.
+fn is_routable_contact_info(value: &CrdsValue, socket_addr_space: &SocketAddrSpace) -> bool {
+    let is_valid_address = |addr: &SocketAddr| {
+        ContactInfo::is_valid_address(addr, socket_addr_space)
+            && (socket_addr_space == &SocketAddrSpace::Unspecified || is_routable_ip(addr.ip()))
+    };
+    match &value.data {
+        CrdsData::LegacyContactInfo(node) => [
+            node.gossip,
+            node.tvu,
+            node.tvu_forwards,
+            node.tpu,
+            node.tpu_forwards,
+            node.tpu_vote,
+            node.serve_repair,
+        ]
+        .iter()
+        .all(|addr| addr.ip().is_unspecified() || is_valid_address(addr)),
+        _ => true,
+    }
+}
+
+// Discards contact-infos which are not routable; see is_routable_contact_info.
+// Returns the discarded values.
+fn retain_routable_contact_infos(
+    values: &mut Vec<CrdsValue>,
+    socket_addr_space: &SocketAddrSpace,
+) -> Vec<CrdsValue> {
+    let (retained, discarded) = std::mem::take(values)
+        .into_iter()
+        .partition(|value| is_routable_contact_info(value, socket_addr_space));
+    *values = retained;
+    discarded
+}
+
 impl ClusterInfo {
     pub fn new(
         contact_info: ContactInfo,
@@ -2435,6 +2505,39 @@ impl ClusterInfo {
                 Protocol::PongMessage(pong) => pong_messages.push((from_addr, pong)),
             }
         }
+        if self.socket_addr_space == SocketAddrSpace::Global {
+            // Pull requests from unroutable callers are dropped, since the
+            // caller's contact-info would otherwise be inserted into crds.
+            let num_pull_requests = pull_requests.len();
+            pull_requests
+                .retain(|(_, _, caller)| is_routable_contact_info(caller, &self.socket_addr_space));
+            let num_discarded: usize = num_pull_requests - pull_requests.len();
+            let num_discarded = num_discarded
+                + push_messages
+                    .iter_mut()
+                    .map(|(_, data)| {
+                        retain_routable_contact_infos(data, &self.socket_addr_space).len()
+                    })
+                    .sum::<usize>();
+            // Record discarded pull responses as failed inserts, so that they
+            // are included in the next pull request filters and peers do not
+            // keep sending them back.
+            let failed_inserts: Vec<Hash> = pull_responses
+                .iter_mut()
+                .flat_map(|(_, data)| retain_routable_contact_infos(data, &self.socket_addr_space))
+                .map(|value| hash(&serialize(&value).unwrap()))
+                .collect();
+            self.stats
+                .discard_unroutable_contact_info
+                .add_relaxed((num_discarded + failed_inserts.len()) as u64);
+            if !failed_inserts.is_empty() {
+                self.gossip
+                    .pull
+                    .record_failed_inserts(failed_inserts, timestamp());
+            }
+            pull_responses.retain(|(_, data)| !data.is_empty());
+            push_messages.retain(|(_, data)| !data.is_empty());
+        }
         if self.require_stake_for_gossip(stakes) {
             for (_, data) in &mut pull_responses {
                 retain_staked(data, stakes);
@@ -3601,6 +3704,143 @@ RPC Enabled Nodes: 1"#;
         });
     }
 
+    #[test]
+    fn test_is_routable_ip() {
+        for ip in ["1.1.1.1", "2606:4700::1111", "::ffff:1.1.1.1"] {
+            assert!(is_routable_ip(ip.parse().unwrap()), "{ip}");
+        }
+        for ip in [
+            "127.0.0.1",
+            "10.0.0.1",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.0.1",
+            "224.0.0.1",
+            "255.255.255.255",
+            "192.0.2.1",
+            "::1",
+            "fd00::1",
+            "fe80::1",
+            "ff02::1",
+            "::ffff:10.0.0.1",
+            "::ffff:127.0.0.1",
+        ] {
+            assert!(!is_routable_ip(ip.parse().unwrap()), "{ip}");
+        }
+    }
+
+    // Returns a contact-info with all sockets set to routable addresses,
+    // modified by the given function.
+    fn new_routable_contact_info<F: FnOnce(&mut ContactInfo)>(f: F) -> CrdsValue {
+        let mut node = ContactInfo {
+            id: Pubkey::new_unique(),
+            gossip: "1.1.1.1:8001".parse().unwrap(),
+            tvu: "1.1.1.1:8002".parse().unwrap(),
+            tvu_forwards: "1.1.1.1:8003".parse().unwrap(),
+            repair: "1.1.1.1:8004".parse().unwrap(),
+            tpu: "1.1.1.1:8005".parse().unwrap(),
+            tpu_forwards: "1.1.1.1:8006".parse().unwrap(),
+            tpu_vote: "1.1.1.1:8007".parse().unwrap(),
+            rpc: "1.1.1.1:8899".parse().unwrap(),
+            rpc_pubsub: "1.1.1.1:8900".parse().unwrap(),
+            serve_repair: "1.1.1.1:8008".parse().unwrap(),
+            wallclock: timestamp(),
+            shred_version: 0,
+        };
+        f(&mut node);
+        CrdsValue::new_unsigned(CrdsData::LegacyContactInfo(node))
+    }
+
+    #[test]
+    fn test_retain_routable_contact_infos() {
+        let other = CrdsValue::new_unsigned(CrdsData::NodeInstance(NodeInstance::new(
+            &mut thread_rng(),
+            Pubkey::new_unique(),
+            timestamp(),
+        )));
+        let unspecified = socketaddr_any!();
+        let values = vec![
+            new_routable_contact_info(|_| ()),
+            // Spy node
+            new_routable_contact_info(|node| {
+                *node = ContactInfo {
+                    id: node.id,
+                    wallclock: node.wallclock,
+                    ..ContactInfo::default()
+                }
+            }),
+            // Node not serving tpu
+            new_routable_contact_info(|node| node.tpu = unspecified),
+            // Sockets peers do not send to unprompted are not checked
+            new_routable_contact_info(|node| node.repair = "10.0.0.1:8004".parse().unwrap()),
+            new_routable_contact_info(|node| node.rpc = "127.0.0.1:8899".parse().unwrap()),
+            other,
+            new_routable_contact_info(|node| node.gossip = "224.0.0.1:8001".parse().unwrap()),
+            new_routable_contact_info(|node| node.tvu = "10.0.0.1:8002".parse().unwrap()),
+            new_routable_contact_info(|node| {
+                node.tvu_forwards = "169.254.0.1:8003".parse().unwrap()
+            }),
+            new_routable_contact_info(|node| node.tpu = "127.0.0.1:8005".parse().unwrap()),
+            new_routable_contact_info(|node| node.tpu_forwards = "[fd00::1]:8006".parse().unwrap()),
+            new_routable_contact_info(|node| {
+                node.tpu_vote = "[::ffff:192.168.1.1]:8007".parse().unwrap()
+            }),
+            new_routable_contact_info(|node| node.serve_repair = "192.0.2.1:8008".parse().unwrap()),
+        ];
+
+        let mut retained = values.clone();
+        assert!(
+            retain_routable_contact_infos(&mut retained, &SocketAddrSpace::Unspecified).is_empty()
+        );
+        assert_eq!(retained, values);
+
+        let mut retained = values.clone();
+        assert_eq!(
+            retain_routable_contact_infos(&mut retained, &SocketAddrSpace::Global),
+            values[6..].to_vec()
+        );
+        assert_eq!(retained, values[..6].to_vec());
+    }
+
+    #[test]
+    fn test_process_packets_drops_unroutable_pull_requests() {
+        let keypair = Arc::new(Keypair::new());
+        let this_node = ContactInfo::new_localhost(&keypair.pubkey(), timestamp());
+        let cluster_info = ClusterInfo::new(this_node, keypair, SocketAddrSpace::Global);
+        let routable = new_routable_contact_info(|_| ());
+        let unroutable =
+            new_routable_contact_info(|node| node.tpu = "10.0.0.1:8005".parse().unwrap());
+        let from_addr: SocketAddr = "1.1.1.1:8001".parse().unwrap();
+        let packets = [&routable, &unroutable]
+            .into_iter()
+            .map(|caller| {
+                let protocol = Protocol::PullRequest(CrdsFilter::default(), caller.clone());
+                (from_addr, protocol)
+            })
+            .collect();
+        let thread_pool = ThreadPoolBuilder::new().build().unwrap();
+        let (response_sender, _response_receiver) = crossbeam_channel::unbounded();
+        cluster_info
+            .process_packets(
+                packets,
+                &thread_pool,
+                &PacketBatchRecycler::default(),
+                &response_sender,
+                &HashMap::new(),
+                None,
+                Duration::from_secs(1),
+                false,
+            )
+            .unwrap();
+        let is_in_crds = |value: &CrdsValue| {
+            cluster_info
+                .lookup_contact_info(&value.pubkey(), |_| ())
+                .is_some()
+        };
+        assert!(is_in_crds(&routable));
+        assert!(!is_in_crds(&unroutable));
+    }
+
     #[test]
     fn test_cluster_info_new() {
         let d = ContactInfo::new_localhost(&solana_sdk::pubkey::new_rand(), timestamp());
@@ -91,12 +91,16 @@ impl<'a, T> Drop for TimedGuard<'a, T> {
 pub struct GossipStats {
     pub(crate) all_tvu_peers: Counter,
     pub(crate) bad_prune_destination: Counter,
+    pub(crate) discard_unroutable_contact_info: Counter,
     pub(crate) entrypoint2: Counter,
     pub(crate) entrypoint: Counter,
     pub(crate) epoch_slots_filled: Counter,
     pub(crate) epoch_slots_lookup: Counter,
     pub(crate) filter_crds_values_dropped_requests: Counter,
     pub(crate) filter_crds_values_dropped_values: Counter,
+    pub(crate) filter_crds_values_false_positive_ppm: Counter,
+    pub(crate) filter_crds_values_num_filters: Counter,
+    pub(crate) filter_crds_values_num_values: Counter,
     pub(crate) filter_pull_response: Counter,
     pub(crate) generate_pull_responses: Counter,
     pub(crate) get_accounts_hash: Counter,
@@ -201,6 +205,17 @@ pub(crate) fn submit_gossip_stats(
         )
     };
     let num_nodes_staked = stakes.values().filter(|stake| **stake > 0).count();
+    let filter_crds_values_num_filters = stats.filter_crds_values_num_filters.clear();
+    let filter_crds_values_num_values = stats.filter_crds_values_num_values.clear();
+    // Averaged over the pull request filters received since the last report.
+    let filter_crds_values_false_positive_ppm = stats
+        .filter_crds_values_false_positive_ppm
+        .clear()
+        .checked_div(filter_crds_values_num_filters)
+        .unwrap_or_default();
+    let filter_crds_values_values_per_filter = filter_crds_values_num_values
+        .checked_div(filter_crds_values_num_filters)
+        .unwrap_or_default();
     datapoint_info!(
         "cluster_info_stats",
         ("entrypoint", stats.entrypoint.clear(), i64),
@@ -291,6 +306,26 @@ pub(crate) fn submit_gossip_stats(
             stats.filter_crds_values_dropped_values.clear(),
             i64
         ),
+        (
+            "filter_crds_values_false_positive_ppm",
+            filter_crds_values_false_positive_ppm,
+            i64
+        ),
+        (
+            "filter_crds_values_num_filters",
+            filter_crds_values_num_filters,
+            i64
+        ),
+        (
+            "filter_crds_values_num_values",
+            filter_crds_values_num_values,
+            i64
+        ),
+        (
+            "filter_crds_values_values_per_filter",
+            filter_crds_values_values_per_filter,
+            i64
+        ),
         (
             "process_pull_resp_count",
             stats.process_pull_response_count.clear(),
@@ -421,6 +456,11 @@ pub(crate) fn submit_gossip_stats(
     );
     datapoint_info!(
         "cluster_info_stats4",
+        (
+            "discard_unroutable_contact_info",
+            stats.discard_unroutable_contact_info.clear(),
+            i64
+        ),
         (
             "skip_push_message_shred_version",
             stats.skip_push_message_shred_version.clear(),
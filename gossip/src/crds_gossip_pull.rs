@@ -42,7 +42,7 @@ use {
         iter::{repeat, repeat_with},
         net::SocketAddr,
         sync::{
-            atomic::{AtomicI64, AtomicUsize, Ordering},
+            atomic::{AtomicI64, AtomicU64, AtomicUsize, Ordering},
             Mutex, RwLock,
         },
         time::Duration,
@@ -382,6 +382,12 @@ impl CrdsGossipPull {
         }
         drop(crds);
         stats.failed_insert += failed_inserts.len();
+        self.record_failed_inserts(failed_inserts, now);
+    }
+
+    /// Records hashes of pull responses which were not inserted, so that they
+    /// are included in subsequent pull request filters.
+    pub(crate) fn record_failed_inserts(&self, failed_inserts: Vec<Hash>, now: u64) {
         self.purge_failed_inserts(now);
         let failed_inserts = failed_inserts.into_iter().zip(repeat(now));
         self.failed_inserts.write().unwrap().extend(failed_inserts);
@@ -456,6 +462,10 @@ impl CrdsGossipPull {
             now.saturating_sub(msg_timeout)..now.saturating_add(msg_timeout);
         let dropped_requests = AtomicUsize::default();
         let total_skipped = AtomicUsize::default();
+        let num_filters = AtomicUsize::default();
+        let num_values = AtomicUsize::default();
+        // Sum of the filters' estimated false positive rates, in parts per million.
+        let false_positive_ppm = AtomicU64::default();
         let output_size_limit = output_size_limit.try_into().unwrap_or(i64::MAX);
         let output_size_limit = AtomicI64::new(output_size_limit);
         let crds = crds.read().unwrap();
@@ -469,6 +479,11 @@ impl CrdsGossipPull {
                 return Vec::default();
             }
             let caller_wallclock = caller_wallclock.checked_add(jitter).unwrap_or(0);
+            num_filters.fetch_add(1, Ordering::Relaxed);
+            false_positive_ppm.fetch_add(
+                (filter.filter.false_positive_rate() * 1_000_000.0) as u64,
+                Ordering::Relaxed,
+            );
             let pred = |entry: &&VersionedCrdsValue| {
                 debug_assert!(filter.test_mask(&entry.value_hash));
                 // Skip values that are too new.
@@ -486,6 +501,7 @@ impl CrdsGossipPull {
                 .take(output_size_limit.load(Ordering::Relaxed).max(0) as usize)
                 .collect();
             output_size_limit.fetch_sub(out.len() as i64, Ordering::Relaxed);
+            num_values.fetch_add(out.len(), Ordering::Relaxed);
             out
         };
         let ret: Vec<_> = thread_pool.install(|| {
@@ -500,6 +516,15 @@ impl CrdsGossipPull {
         stats
             .filter_crds_values_dropped_values
             .add_relaxed(total_skipped.into_inner() as u64);
+        stats
+            .filter_crds_values_false_positive_ppm
+            .add_relaxed(false_positive_ppm.into_inner());
+        stats
+            .filter_crds_values_num_filters
+            .add_relaxed(num_filters.into_inner() as u64);
+        stats
+            .filter_crds_values_num_values
+            .add_relaxed(num_values.into_inner() as u64);
         ret
     }
 
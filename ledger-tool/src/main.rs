@@ -973,6 +973,9 @@ fn raw_key_to_slot(key: &[u8], column_name: &str) -> Option<Slot> {
         cf::OptimisticSlots::NAME => {
             Some(cf::OptimisticSlots::slot(cf::OptimisticSlots::index(key)))
         }
+        // does not implement slot(); like ProgramCosts, deliberately excluded
+        // from slot-based purge and compaction
+        cf::ServiceMetadata::NAME => None,
         &_ => None,
     }
 }
@@ -7,7 +7,7 @@ use {
         ancestor_iterator::AncestorIterator,
         blockstore_db::{
             columns as cf, Column, Database, IteratorDirection, IteratorMode, LedgerColumn, Result,
-            WriteBatch,
+            WriteBatch, MAX_SERVICE_METADATA_NAMESPACE_LEN,
         },
         blockstore_meta::*,
         blockstore_options::{
@@ -32,6 +32,7 @@ use {
         ThreadPool,
     },
     rocksdb::{DBRawIterator, LiveFile},
+    serde::{de::DeserializeOwned, Serialize},
     solana_entry::entry::{create_ticks, Entry},
     solana_measure::measure::Measure,
     solana_metrics::{
@@ -173,6 +174,7 @@ pub struct Blockstore {
     program_costs_cf: LedgerColumn<cf::ProgramCosts>,
     bank_hash_cf: LedgerColumn<cf::BankHash>,
     optimistic_slots_cf: LedgerColumn<cf::OptimisticSlots>,
+    service_metadata_cf: LedgerColumn<cf::ServiceMetadata>,
     last_root: RwLock<Slot>,
     insert_shreds_lock: Mutex<()>,
     new_shreds_signals: Mutex<Vec<Sender<bool>>>,
@@ -278,6 +280,7 @@ impl Blockstore {
         let program_costs_cf = db.column();
         let bank_hash_cf = db.column();
         let optimistic_slots_cf = db.column();
+        let service_metadata_cf = db.column();
 
         let db = Arc::new(db);
 
@@ -330,6 +333,7 @@ impl Blockstore {
             program_costs_cf,
             bank_hash_cf,
             optimistic_slots_cf,
+            service_metadata_cf,
             new_shreds_signals: Mutex::default(),
             completed_slots_senders: Mutex::default(),
             shred_timing_point_sender: None,
@@ -688,6 +692,7 @@ impl Blockstore {
         self.program_costs_cf.submit_rocksdb_cf_metrics();
         self.bank_hash_cf.submit_rocksdb_cf_metrics();
         self.optimistic_slots_cf.submit_rocksdb_cf_metrics();
+        self.service_metadata_cf.submit_rocksdb_cf_metrics();
     }
 
     fn try_shred_recovery(
@@ -2770,6 +2775,58 @@ impl Blockstore {
         self.program_costs_cf.delete(*key)
     }
 
+    fn service_metadata_index(namespace: &str, key: &str) -> Result<(String, String)> {
+        if namespace.len() > MAX_SERVICE_METADATA_NAMESPACE_LEN {
+            return Err(BlockstoreError::ServiceMetadataNamespaceTooLong);
+        }
+        Ok((namespace.to_string(), key.to_string()))
+    }
+
+    /// Returns the value a service stored under `key` in its `namespace`, if any
+    pub fn read_service_metadata<T: DeserializeOwned>(
+        &self,
+        namespace: &str,
+        key: &str,
+    ) -> Result<Option<T>> {
+        self.service_metadata_cf
+            .get_bytes(Self::service_metadata_index(namespace, key)?)?
+            .map(|bytes| deserialize(&bytes).map_err(BlockstoreError::from))
+            .transpose()
+    }
+
+    /// Stores `value` under `key` in the service's `namespace`, replacing any
+    /// previous value
+    pub fn write_service_metadata<T: Serialize>(
+        &self,
+        namespace: &str,
+        key: &str,
+        value: &T,
+    ) -> Result<()> {
+        let index = Self::service_metadata_index(namespace, key)?;
+        let bytes = serialize(value)?;
+        self.service_metadata_cf.put_bytes(index, &bytes)
+    }
+
+    pub fn delete_service_metadata(&self, namespace: &str, key: &str) -> Result<()> {
+        self.service_metadata_cf
+            .delete(Self::service_metadata_index(namespace, key)?)
+    }
+
+    /// Returns all keys and their raw values stored in the service's `namespace`
+    pub fn service_metadata_iterator(
+        &self,
+        namespace: &str,
+    ) -> Result<impl Iterator<Item = (String, Box<[u8]>)> + '_> {
+        let (namespace, key) = Self::service_metadata_index(namespace, "")?;
+        let iter = self.db.iter::<cf::ServiceMetadata>(IteratorMode::From(
+            (namespace.clone(), key),
+            IteratorDirection::Forward,
+        ))?;
+        Ok(iter
+            .take_while(move |((entry_namespace, _), _)| *entry_namespace == namespace)
+            .map(|((_, key), value)| (key, value)))
+    }
+
     /// Returns the entry vector for the slot starting with `shred_start_index`
     pub fn get_slot_entries(&self, slot: Slot, shred_start_index: u64) -> Result<Vec<Entry>> {
         self.get_slot_entries_with_shred_info(slot, shred_start_index, false)
@@ -9780,4 +9837,110 @@ pub mod tests {
             assert_eq!(read_cost, *cost_table.get(&read_key).unwrap());
         }
     }
+
+    #[test]
+    fn test_read_write_service_metadata() {
+        let ledger_path = get_tmp_ledger_path_auto_delete!();
+        let blockstore = Blockstore::open(ledger_path.path()).unwrap();
+
+        assert_eq!(
+            blockstore
+                .read_service_metadata::<u64>("service_a", "counter")
+                .unwrap(),
+            None
+        );
+
+        blockstore
+            .write_service_metadata("service_a", "counter", &42u64)
+            .unwrap();
+        blockstore
+            .write_service_metadata("service_a", "name", &"alpha".to_string())
+            .unwrap();
+        // Same key in a different namespace, and a namespace sharing a prefix
+        blockstore
+            .write_service_metadata("service_b", "counter", &7u64)
+            .unwrap();
+        blockstore
+            .write_service_metadata("service_ab", "counter", &9u64)
+            .unwrap();
+
+        assert_eq!(
+            blockstore
+                .read_service_metadata::<u64>("service_a", "counter")
+                .unwrap(),
+            Some(42)
+        );
+        assert_eq!(
+            blockstore
+                .read_service_metadata::<String>("service_a", "name")
+                .unwrap(),
+            Some("alpha".to_string())
+        );
+        assert_eq!(
+            blockstore
+                .read_service_metadata::<u64>("service_b", "counter")
+                .unwrap(),
+            Some(7)
+        );
+
+        let keys: Vec<_> = blockstore
+            .service_metadata_iterator("service_a")
+            .unwrap()
+            .map(|(key, _)| key)
+            .collect();
+        assert_eq!(keys, vec!["counter".to_string(), "name".to_string()]);
+
+        // overwrite
+        blockstore
+            .write_service_metadata("service_a", "counter", &43u64)
+            .unwrap();
+        assert_eq!(
+            blockstore
+                .read_service_metadata::<u64>("service_a", "counter")
+                .unwrap(),
+            Some(43)
+        );
+
+        // delete only affects the given namespace
+        blockstore
+            .delete_service_metadata("service_a", "counter")
+            .unwrap();
+        assert_eq!(
+            blockstore
+                .read_service_metadata::<u64>("service_a", "counter")
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            blockstore
+                .read_service_metadata::<u64>("service_b", "counter")
+                .unwrap(),
+            Some(7)
+        );
+
+        // Namespaces too long to encode are rejected rather than panicking
+        let long_namespace = "a".repeat(MAX_SERVICE_METADATA_NAMESPACE_LEN + 1);
+        assert_matches!(
+            blockstore.write_service_metadata(&long_namespace, "counter", &1u64),
+            Err(BlockstoreError::ServiceMetadataNamespaceTooLong)
+        );
+        assert_matches!(
+            blockstore.read_service_metadata::<u64>(&long_namespace, "counter"),
+            Err(BlockstoreError::ServiceMetadataNamespaceTooLong)
+        );
+        assert_matches!(
+            blockstore.delete_service_metadata(&long_namespace, "counter"),
+            Err(BlockstoreError::ServiceMetadataNamespaceTooLong)
+        );
+        let namespace = "a".repeat(MAX_SERVICE_METADATA_NAMESPACE_LEN);
+        blockstore
+            .write_service_metadata(&namespace, "counter", &1u64)
+            .unwrap();
+        assert_eq!(
+            blockstore
+                .read_service_metadata::<u64>(&namespace, "counter")
+                .unwrap(),
+            Some(1)
+        );
+    }
 }
@@ -94,6 +94,12 @@ const BLOCK_HEIGHT_CF: &str = "block_height";
 const PROGRAM_COSTS_CF: &str = "program_costs";
 /// Column family for optimistic slots
 const OPTIMISTIC_SLOTS_CF: &str = "optimistic_slots";
+/// Column family for ServiceMetadata
+const SERVICE_METADATA_CF: &str = "service_metadata";
+
+/// Maximum length in bytes of a `ServiceMetadata` namespace, which is
+/// length-prefixed with a single byte in the column key
+pub const MAX_SERVICE_METADATA_NAMESPACE_LEN: usize = u8::MAX as usize;
 
 #[derive(Error, Debug)]
 pub enum BlockstoreError {
@@ -117,6 +123,7 @@ pub enum BlockstoreError {
     SlotUnavailable,
     UnsupportedTransactionVersion,
     MissingTransactionMetadata,
+    ServiceMetadataNamespaceTooLong,
 }
 pub type Result<T> = std::result::Result<T, BlockstoreError>;
 
@@ -309,6 +316,16 @@ pub mod columns {
     /// value type: `blockstore_meta::OptimisticSlotMetaVersioned`
     pub struct OptimisticSlots;
 
+    #[derive(Debug)]
+    /// The service metadata column
+    ///
+    /// Holds small, ad hoc values that services need to persist across
+    /// restarts, namespaced by service so they do not collide.
+    ///
+    /// index type: `(String, String)` (namespace, key)
+    /// value type: bincode serialized bytes, owned by the service
+    pub struct ServiceMetadata;
+
     // When adding a new column ...
     // - Add struct below and implement `Column` and `ColumnName` traits
     // - Add descriptor in Rocks::cf_descriptors() and name in Rocks::columns()
@@ -435,6 +452,7 @@ impl Rocks {
             new_cf_descriptor::<BlockHeight>(options, oldest_slot),
             new_cf_descriptor::<ProgramCosts>(options, oldest_slot),
             new_cf_descriptor::<OptimisticSlots>(options, oldest_slot),
+            new_cf_descriptor::<ServiceMetadata>(options, oldest_slot),
         ]
     }
 
@@ -462,6 +480,7 @@ impl Rocks {
             BlockHeight::NAME,
             ProgramCosts::NAME,
             OptimisticSlots::NAME,
+            ServiceMetadata::NAME,
         ]
     }
 
@@ -874,6 +893,53 @@ impl Column for columns::ProgramCosts {
     }
 }
 
+impl ColumnName for columns::ServiceMetadata {
+    const NAME: &'static str = SERVICE_METADATA_CF;
+}
+impl Column for columns::ServiceMetadata {
+    type Index = (String, String);
+
+    fn key((namespace, key): (String, String)) -> Vec<u8> {
+        // Blockstore checks the namespace length before building the index.
+        assert!(namespace.len() <= MAX_SERVICE_METADATA_NAMESPACE_LEN);
+        let mut bytes = Vec::with_capacity(1 + namespace.len() + key.len());
+        bytes.push(namespace.len() as u8);
+        bytes.extend_from_slice(namespace.as_bytes());
+        bytes.extend_from_slice(key.as_bytes());
+        bytes
+    }
+
+    fn index(key: &[u8]) -> Self::Index {
+        let namespace_end = 1 + key.first().copied().unwrap_or_default() as usize;
+        // Blockstore never writes empty or truncated keys; treat any such key
+        // as having an empty namespace instead of panicking.
+        let (namespace, key) = match key.get(1..namespace_end) {
+            Some(namespace) => (namespace, &key[namespace_end..]),
+            None => (&[][..], key),
+        };
+        (
+            String::from_utf8_lossy(namespace).into_owned(),
+            String::from_utf8_lossy(key).into_owned(),
+        )
+    }
+
+    // Like ProgramCosts, this column is not keyed by slot and is deliberately
+    // excluded from slot-based purge and compaction.
+    fn primary_index(_index: Self::Index) -> u64 {
+        unimplemented!()
+    }
+
+    // See primary_index() above.
+    fn slot(_index: Self::Index) -> Slot {
+        unimplemented!()
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn as_index(_index: u64) -> Self::Index {
+        (String::default(), String::default())
+    }
+}
+
 impl Column for columns::ShredCode {
     type Index = (u64, u64);
 
@@ -1381,6 +1447,23 @@ where
         result
     }
 
+    pub fn delete(&self, key: C::Index) -> Result<()> {
+        let is_perf_enabled = maybe_enable_rocksdb_perf(
+            self.column_options.rocks_perf_sample_interval,
+            &self.write_perf_status,
+        );
+        let result = self.backend.delete_cf(self.handle(), &C::key(key));
+        if let Some(op_start_instant) = is_perf_enabled {
+            report_rocksdb_write_perf(
+                C::NAME,
+                "delete",
+                &op_start_instant.elapsed(),
+                &self.column_options,
+            );
+        }
+        result
+    }
+
     /// Retrieves the specified RocksDB integer property of the current
     /// column family.
     ///
@@ -1472,23 +1555,6 @@ where
         }
         result
     }
-
-    pub fn delete(&self, key: C::Index) -> Result<()> {
-        let is_perf_enabled = maybe_enable_rocksdb_perf(
-            self.column_options.rocks_perf_sample_interval,
-            &self.write_perf_status,
-        );
-        let result = self.backend.delete_cf(self.handle(), &C::key(key));
-        if let Some(op_start_instant) = is_perf_enabled {
-            report_rocksdb_write_perf(
-                C::NAME,
-                "delete",
-                &op_start_instant.elapsed(),
-                &self.column_options,
-            );
-        }
-        result
-    }
 }
 
 impl<C> LedgerColumn<C>
@@ -1855,6 +1921,7 @@ fn should_exclude_from_compaction(cf_name: &str) -> bool {
         columns::TransactionStatusIndex::NAME,
         columns::ProgramCosts::NAME,
         columns::TransactionMemos::NAME,
+        columns::ServiceMetadata::NAME,
     ]
     .into_iter()
     .collect();
@@ -1943,7 +2010,7 @@ pub mod tests {
 
     #[test]
     fn test_should_exclude_from_compaction() {
-        // currently there are three CFs excluded from compaction:
+        // currently there are four CFs excluded from compaction:
         assert!(should_exclude_from_compaction(
             columns::TransactionStatusIndex::NAME
         ));
@@ -1951,6 +2018,29 @@ pub mod tests {
         assert!(should_exclude_from_compaction(
             columns::TransactionMemos::NAME
         ));
+        assert!(should_exclude_from_compaction(
+            columns::ServiceMetadata::NAME
+        ));
         assert!(!should_exclude_from_compaction("something else"));
     }
+
+    #[test]
+    fn test_service_metadata_index() {
+        let index = ("service".to_string(), "key".to_string());
+        let key = columns::ServiceMetadata::key(index.clone());
+        assert_eq!(columns::ServiceMetadata::index(&key), index);
+
+        // Malformed keys do not panic
+        assert_eq!(
+            columns::ServiceMetadata::index(&[]),
+            (String::default(), String::default())
+        );
+        assert_eq!(
+            columns::ServiceMetadata::index(&key[..3]),
+            (
+                String::default(),
+                String::from_utf8_lossy(&key[..3]).into_owned()
+            )
+        );
+    }
 }
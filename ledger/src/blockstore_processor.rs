@@ -1127,7 +1127,11 @@ fn confirm_slot_entries(
         datapoint_debug!("verify-batch-size", ("size", num_entries as i64, i64));
         let entry_state = entries.start_verify(&progress.last_entry, recyclers.clone());
         if entry_state.status() == EntryVerificationStatus::Failure {
-            warn!("Ledger proof of history failed at slot: {}", slot);
+            warn!(
+                "Ledger proof of history failed at slot: {}, entry: {:?}",
+                slot,
+                entry_state.first_invalid_entry()
+            );
             return Err(BlockError::InvalidEntryHash.into());
         }
         Some(entry_state)
@@ -1202,7 +1206,11 @@ fn confirm_slot_entries(
                 timing.transaction_verify_elapsed +=
                     transaction_cpu_duration_us + check_result.gpu_verify_duration();
                 if !verified {
-                    warn!("Ledger proof of history failed at slot: {}", bank.slot());
+                    warn!(
+                        "Ledger proof of history failed at slot: {}, entry: {:?}",
+                        bank.slot(),
+                        verifier.first_invalid_entry()
+                    );
                     return Err(BlockError::InvalidEntryHash.into());
                 }
             }
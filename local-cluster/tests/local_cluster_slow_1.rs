@@ -42,7 +42,7 @@ use {
             Arc,
         },
         thread::sleep,
-        time::Duration,
+        time::{Duration, Instant},
     },
 };
 
@@ -305,6 +305,136 @@ fn test_kill_heaviest_partition() {
     )
 }
 
+#[test]
+#[serial]
+fn test_partitioned_node_repairs_heaviest_fork() {
+    // This test:
+    // 1) Spins up a heavy and a light partition that are both scheduled as leader
+    // during the partition, so each of them builds its own fork
+    // 2) Resolves the partition. Repair on the light node is weighted by the votes
+    // it observes, so it should repair and switch to the heavy fork instead of
+    // completing its own abandoned fork
+    // 3) Checks that the light node's new roots are on the heavy fork, and that
+    // its rooted chain through the partition is made of repaired heavy leader slots
+    //
+    // The heavy partition holds 90% of the stake, so consensus alone forces the
+    // light node onto the heavy fork. This is a regression guard for the light
+    // node repairing and rooting that fork, not a test of repair prioritization
+    // itself, which is covered by the `RepairWeight` unit tests.
+    let num_slots_per_validator = 8;
+    let partitions: [usize; 2] = [9 * DEFAULT_NODE_STAKE as usize, DEFAULT_NODE_STAKE as usize];
+    let (leader_schedule, validator_keys) = create_custom_leader_schedule_with_random_keys(&[
+        num_slots_per_validator * 3,
+        num_slots_per_validator,
+    ]);
+
+    let heavy_validator = validator_keys[0].pubkey();
+    let light_validator = validator_keys[1].pubkey();
+    // Same layout as `leader_schedule`, used to look up slot leaders after the
+    // schedule has been moved into the cluster
+    let slot_leaders = create_custom_leader_schedule(
+        [heavy_validator, light_validator]
+            .into_iter()
+            .zip([num_slots_per_validator * 3, num_slots_per_validator]),
+    );
+    let highest_slot = |cluster: &LocalCluster, pubkey: &Pubkey| -> Slot {
+        open_blockstore(&cluster.ledger_path(pubkey))
+            .highest_slot()
+            .unwrap()
+            .unwrap_or_default()
+    };
+
+    // The context records the range of slots produced while turbine was
+    // disabled. Heavy leader slots in that range can only reach the light node
+    // through repair.
+    let on_partition_start = |cluster: &mut LocalCluster, partition_slots: &mut (Slot, Slot)| {
+        partition_slots.0 = highest_slot(cluster, &heavy_validator);
+    };
+    let on_before_partition_resolved =
+        |cluster: &mut LocalCluster, partition_slots: &mut (Slot, Slot)| {
+            partition_slots.1 = highest_slot(cluster, &heavy_validator);
+        };
+    let on_partition_resolved = |cluster: &mut LocalCluster, partition_slots: &mut (Slot, Slot)| {
+        let (partition_start, partition_end) = *partition_slots;
+        cluster.check_for_new_roots(16, "PARTITION_TEST", SocketAddrSpace::Unspecified);
+
+        // Wait for the light node to root past the partition, and for the heavy
+        // node to catch up to that root, so the checks below don't race with
+        // either node's own rooting
+        let timeout = Duration::from_secs(60);
+        let now = Instant::now();
+        let light_blockstore = loop {
+            let light_blockstore = open_blockstore(&cluster.ledger_path(&light_validator));
+            if light_blockstore.max_root() > partition_end {
+                break light_blockstore;
+            }
+            assert!(
+                now.elapsed() < timeout,
+                "light node did not root past the partition"
+            );
+            sleep(Duration::from_millis(100));
+        };
+        let light_root = light_blockstore.max_root();
+        let heavy_blockstore = loop {
+            let heavy_blockstore = open_blockstore(&cluster.ledger_path(&heavy_validator));
+            if heavy_blockstore.max_root() >= light_root {
+                break heavy_blockstore;
+            }
+            assert!(
+                now.elapsed() < timeout,
+                "heavy node did not reach the light node's root {light_root}"
+            );
+            sleep(Duration::from_millis(100));
+        };
+        info!(
+            "PARTITION_TEST light node root: {}, heavy node root: {}, partition slots: {}..={}",
+            light_root,
+            heavy_blockstore.max_root(),
+            partition_start,
+            partition_end,
+        );
+
+        // The light node's rooted chain must match the heavy node's roots
+        let light_rooted_chain: Vec<Slot> = std::iter::once(light_root)
+            .chain(AncestorIterator::new(light_root, &light_blockstore))
+            .take_while(|slot| *slot >= partition_start)
+            .collect();
+        for slot in &light_rooted_chain {
+            assert!(
+                heavy_blockstore.is_root(*slot),
+                "light node rooted slot {slot} which is not on the heavy fork"
+            );
+        }
+
+        // Within the partition, that chain must be made of repaired heavy leader
+        // slots, i.e. the light node abandoned its own fork
+        let partition_chain: Vec<Slot> = light_rooted_chain
+            .into_iter()
+            .filter(|slot| (partition_start + 1..=partition_end).contains(slot))
+            .collect();
+        assert!(
+            !partition_chain.is_empty(),
+            "light node did not repair any of the heavy fork's partition slots"
+        );
+        for slot in partition_chain {
+            assert_eq!(
+                slot_leaders[slot], heavy_validator,
+                "light node rooted its own partition slot {slot}"
+            );
+        }
+    };
+    run_cluster_partition(
+        &partitions,
+        Some((leader_schedule, validator_keys)),
+        (0, 0),
+        on_partition_start,
+        on_before_partition_resolved,
+        on_partition_resolved,
+        None,
+        vec![],
+    )
+}
+
 #[test]
 #[serial]
 fn test_kill_partition_switch_threshold_no_progress() {
@@ -14,14 +14,22 @@ use {
         local_cluster::{ClusterConfig, LocalCluster},
         validator_configs::*,
     },
+    solana_rpc_client::rpc_client::RpcClient,
     solana_sdk::{
         client::SyncClient,
         clock::Slot,
+        commitment_config::CommitmentConfig,
+        epoch_schedule::MINIMUM_SLOTS_PER_EPOCH,
         poh_config::PohConfig,
         signature::{Keypair, Signer},
     },
     solana_streamer::socket::SocketAddrSpace,
-    std::{collections::HashSet, sync::Arc, thread::sleep, time::Duration},
+    std::{
+        collections::{HashMap, HashSet},
+        sync::Arc,
+        thread::sleep,
+        time::Duration,
+    },
 };
 
 mod common;
@@ -441,3 +449,151 @@ fn test_slot_hash_expiry() {
         "test_slot_hashes_expiry",
     );
 }
+
+// Stake a new validator mid-epoch and check that it only enters the leader
+// schedule at an epoch boundary, that each epoch's leader schedule is fixed once
+// computed, that the cluster keeps rooting slots across the boundaries, and that
+// every rooted slot was produced by its scheduled leader.
+#[test]
+#[serial]
+fn test_leader_schedule_changes_at_epoch_boundary() {
+    solana_logger::setup_with_default(RUST_LOG_FILTER);
+    error!("test_leader_schedule_changes_at_epoch_boundary");
+    let slots_per_epoch = MINIMUM_SLOTS_PER_EPOCH;
+    let validator_config = ValidatorConfig::default_for_test();
+    let mut config = ClusterConfig {
+        cluster_lamports: DEFAULT_CLUSTER_LAMPORTS,
+        node_stakes: vec![DEFAULT_NODE_STAKE; 2],
+        validator_configs: make_identical_validator_configs(&validator_config, 2),
+        slots_per_epoch,
+        stakers_slot_offset: slots_per_epoch,
+        skip_warmup_slots: true,
+        ..ClusterConfig::default()
+    };
+    let mut cluster = LocalCluster::new(&mut config, SocketAddrSpace::Unspecified);
+    let epoch_schedule = cluster.genesis_config.epoch_schedule.clone();
+    let client = RpcClient::new_socket(cluster.entry_point_info.rpc);
+    // The leader schedule returned by rpc is the one of the epoch containing
+    // the given slot
+    let get_leader_schedule_at_slot = |slot| {
+        client
+            .get_leader_schedule_with_commitment(Some(slot), CommitmentConfig::confirmed())
+            .unwrap()
+    };
+    let get_leader_schedule =
+        |epoch| get_leader_schedule_at_slot(epoch_schedule.get_first_slot_in_epoch(epoch));
+
+    // Wait until the middle of the first full epoch
+    let start_epoch = loop {
+        let epoch_info = client.get_epoch_info().unwrap();
+        if epoch_info.epoch >= 1 && epoch_info.slot_index >= slots_per_epoch / 2 {
+            break epoch_info.epoch;
+        }
+        sleep(Duration::from_millis(400));
+    };
+    let next_epoch_schedule = get_leader_schedule(start_epoch + 1).unwrap();
+
+    info!("Staking a new validator in epoch {}", start_epoch);
+    let new_validator_keypair = Arc::new(Keypair::new());
+    let new_validator_pubkey = new_validator_keypair.pubkey();
+    cluster.add_validator(
+        &validator_config,
+        DEFAULT_NODE_STAKE * 2,
+        new_validator_keypair,
+        None,
+        SocketAddrSpace::Unspecified,
+    );
+    // The stake was delegated somewhere between `start_epoch` and now
+    let delegated_epoch = client.get_epoch_info().unwrap().epoch;
+
+    // The stake activates in the epoch after it's delegated, and leader schedules
+    // are computed one epoch ahead, so the schedule of the next epoch must not
+    // have changed
+    assert_eq!(
+        get_leader_schedule(start_epoch + 1),
+        Some(next_epoch_schedule)
+    );
+
+    let mut leader_schedules = HashMap::new();
+    let first_scheduled_epoch = loop {
+        let epoch = client.get_epoch_info().unwrap().epoch;
+        assert!(
+            epoch <= start_epoch + 6,
+            "new validator was never added to the leader schedule"
+        );
+        if let Some(leader_schedule) = get_leader_schedule(epoch + 1) {
+            let is_scheduled = leader_schedule.contains_key(&new_validator_pubkey.to_string());
+            if let Some(previous) = leader_schedules.insert(epoch + 1, leader_schedule) {
+                assert_eq!(&previous, &leader_schedules[&(epoch + 1)]);
+            }
+            if is_scheduled {
+                break epoch + 1;
+            }
+        }
+        sleep(Duration::from_millis(400));
+    };
+    info!(
+        "New validator delegated in epoch {}..={}, scheduled from epoch {}",
+        start_epoch, delegated_epoch, first_scheduled_epoch
+    );
+    // The stake is only eligible from the epoch after activation, but is still
+    // warming up then, so with few leader slots per epoch the new validator may
+    // not be drawn until a later epoch
+    assert!(first_scheduled_epoch >= start_epoch + 2);
+
+    // The schedule must switch exactly at the epoch boundary: the last slot of
+    // the previous epoch still uses the old schedule, and the first slot of
+    // the activation epoch uses the new one
+    let activation_slot = epoch_schedule.get_first_slot_in_epoch(first_scheduled_epoch);
+    let new_validator = new_validator_pubkey.to_string();
+    let last_schedule_before = get_leader_schedule_at_slot(activation_slot - 1).unwrap();
+    assert!(!last_schedule_before.contains_key(&new_validator));
+    assert_eq!(
+        Some(last_schedule_before),
+        get_leader_schedule(first_scheduled_epoch - 1)
+    );
+    let first_schedule_after = get_leader_schedule_at_slot(activation_slot).unwrap();
+    assert!(first_schedule_after.contains_key(&new_validator));
+    assert_eq!(
+        first_schedule_after,
+        leader_schedules[&first_scheduled_epoch]
+    );
+
+    // Votes keep landing across the epoch boundaries
+    cluster.check_for_new_roots(
+        16,
+        "test_leader_schedule_changes_at_epoch_boundary",
+        SocketAddrSpace::Unspecified,
+    );
+
+    let last_slot = client
+        .get_slot_with_commitment(CommitmentConfig::finalized())
+        .unwrap();
+    let slot_leaders = client.get_slot_leaders(1, last_slot).unwrap();
+    // The new validator leads no slot before the activation epoch
+    assert!(slot_leaders
+        .iter()
+        .take((activation_slot - 1) as usize)
+        .all(|leader| *leader != new_validator_pubkey));
+    let leader_ledger_path = cluster.ledger_path(&cluster.entry_point_info.id);
+    cluster.close_preserve_ledgers();
+
+    let blockstore = open_blockstore(&leader_ledger_path);
+    let rooted_slots: Vec<_> = blockstore
+        .rooted_slot_iterator(1)
+        .unwrap()
+        .take_while(|slot| *slot <= last_slot)
+        .collect();
+    assert!(rooted_slots
+        .iter()
+        .any(|slot| epoch_schedule.get_epoch(*slot) >= first_scheduled_epoch));
+    for slot in rooted_slots {
+        let leader = slot_leaders[(slot - 1) as usize];
+        let shreds = blockstore.get_data_shreds_for_slot(slot, 0).unwrap();
+        assert!(!shreds.is_empty());
+        assert!(
+            shreds.iter().all(|shred| shred.verify(&leader)),
+            "slot {slot} was not produced by its scheduled leader {leader}"
+        );
+    }
+}
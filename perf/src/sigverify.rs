@@ -42,6 +42,15 @@ const TRACER_KEY_OFFSET_IN_TRANSACTION: usize = 69;
 // Empirically derived to constrain max verify latency to ~8ms at lower packet counts
 pub const VERIFY_MIN_PACKETS_PER_THREAD: usize = 128;
 
+// micro-benchmarks show GPU time for smallest batch around 15-20ms
+// and CPU speed for 64-128 sigverifies around 10-20ms. 64 is a nice
+// power-of-two number around that accounting for the fact that the CPU
+// may be busy doing other things while being a real validator
+// TODO: dynamically adjust this crossover
+pub const GPU_VERIFY_MIN_PACKETS: usize = 64;
+// Minimum percentage of valid packets for a batch to be worth copying to the GPU
+const GPU_VERIFY_MIN_VALID_PACKET_PCT: usize = 90;
+
 lazy_static! {
     static ref PAR_THREAD_POOL: ThreadPool = rayon::ThreadPoolBuilder::new()
         .num_threads(get_thread_count())
@@ -717,31 +726,35 @@ pub fn mark_disabled(batches: &mut [PacketBatch], r: &[Vec<u8>]) {
     }
 }
 
+/// Returns true if a batch is large and dense enough to be dispatched to the GPU,
+/// smaller or sparser batches spill over to the CPU thread pool.
+pub fn is_gpu_verify_batch(valid_packet_count: usize, total_packet_count: usize) -> bool {
+    valid_packet_count >= GPU_VERIFY_MIN_PACKETS
+        && 100usize
+            .saturating_mul(valid_packet_count)
+            .saturating_div(total_packet_count)
+            >= GPU_VERIFY_MIN_VALID_PACKET_PCT
+}
+
+/// Verifies the batches on the GPU if available and the batches are worth
+/// dispatching to it, or on the CPU otherwise. Returns true if the batches
+/// were verified on the GPU.
 pub fn ed25519_verify(
     batches: &mut [PacketBatch],
     recycler: &Recycler<TxOffset>,
     recycler_out: &Recycler<PinnedVec<u8>>,
     reject_non_vote: bool,
     valid_packet_count: usize,
-) {
+) -> bool {
     let api = match perf_libs::api() {
-        None => return ed25519_verify_cpu(batches, reject_non_vote, valid_packet_count),
-        Some(api) => api,
+        Some(api) if is_gpu_verify_batch(valid_packet_count, count_packets_in_batches(batches)) => {
+            api
+        }
+        _ => {
+            ed25519_verify_cpu(batches, reject_non_vote, valid_packet_count);
+            return false;
+        }
     };
-    let total_packet_count = count_packets_in_batches(batches);
-    // micro-benchmarks show GPU time for smallest batch around 15-20ms
-    // and CPU speed for 64-128 sigverifies around 10-20ms. 64 is a nice
-    // power-of-two number around that accounting for the fact that the CPU
-    // may be busy doing other things while being a real validator
-    // TODO: dynamically adjust this crossover
-    if valid_packet_count < 64
-        || 100usize
-            .wrapping_mul(valid_packet_count)
-            .wrapping_div(total_packet_count)
-            < 90
-    {
-        return ed25519_verify_cpu(batches, reject_non_vote, valid_packet_count);
-    }
 
     let (signature_offsets, pubkey_offsets, msg_start_offsets, msg_sizes, sig_lens) =
         generate_offsets(batches, recycler, reject_non_vote);
@@ -791,6 +804,7 @@ pub fn ed25519_verify(
     copy_return_values(sig_lens, &out, &mut rvs);
     mark_disabled(batches, &rvs);
     inc_new_counter_debug!("ed25519_verify_gpu", valid_packet_count);
+    true
 }
 
 #[cfg(test)]
@@ -1244,6 +1258,22 @@ mod tests {
         sigverify::ed25519_verify(batches, &recycler, &recycler_out, false, packet_count);
     }
 
+    #[test]
+    fn test_is_gpu_verify_batch() {
+        assert!(!is_gpu_verify_batch(0, 0));
+        assert!(!is_gpu_verify_batch(
+            GPU_VERIFY_MIN_PACKETS - 1,
+            GPU_VERIFY_MIN_PACKETS - 1
+        ));
+        assert!(is_gpu_verify_batch(
+            GPU_VERIFY_MIN_PACKETS,
+            GPU_VERIFY_MIN_PACKETS
+        ));
+        assert!(is_gpu_verify_batch(90, 100));
+        // Too many discarded packets to be worth the copy to the GPU
+        assert!(!is_gpu_verify_batch(89, 100));
+    }
+
     #[test]
     fn test_verify_tampered_sig_len() {
         let mut tx = test_tx();
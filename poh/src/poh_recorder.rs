@@ -222,6 +222,7 @@ pub struct PohRecorder {
     leader_first_tick_height_including_grace_ticks: Option<u64>,
     leader_last_tick_height: u64, // zero if none
     grace_ticks: u64,
+    max_grace_slots: u64,
     id: Pubkey,
     blockstore: Arc<Blockstore>,
     leader_schedule_cache: Arc<LeaderScheduleCache>,
@@ -257,7 +258,11 @@ impl PohRecorder {
                 leader_first_tick_height_including_grace_ticks,
                 leader_last_tick_height,
                 grace_ticks,
-            ) = Self::compute_leader_slot_tick_heights(next_leader_slot, self.ticks_per_slot);
+            ) = Self::compute_leader_slot_tick_heights(
+                next_leader_slot,
+                self.ticks_per_slot,
+                self.max_grace_slots,
+            );
             self.grace_ticks = grace_ticks;
             self.leader_first_tick_height_including_grace_ticks =
                 leader_first_tick_height_including_grace_ticks;
@@ -431,6 +436,7 @@ impl PohRecorder {
     fn compute_leader_slot_tick_heights(
         next_leader_slot: Option<(Slot, Slot)>,
         ticks_per_slot: u64,
+        max_grace_slots: u64,
     ) -> (Option<u64>, u64, u64) {
         next_leader_slot
             .map(|(first_slot, last_slot)| {
@@ -438,7 +444,7 @@ impl PohRecorder {
                 let last_tick_height = (last_slot + 1) * ticks_per_slot;
                 let num_slots = last_slot - first_slot + 1;
                 let grace_ticks = cmp::min(
-                    ticks_per_slot * MAX_GRACE_SLOTS,
+                    ticks_per_slot * max_grace_slots,
                     ticks_per_slot * num_slots / GRACE_TICKS_FACTOR,
                 );
                 let leader_first_tick_height_including_grace_ticks =
@@ -453,7 +459,7 @@ impl PohRecorder {
                 None,
                 0,
                 cmp::min(
-                    ticks_per_slot * MAX_GRACE_SLOTS,
+                    ticks_per_slot * max_grace_slots,
                     ticks_per_slot * NUM_CONSECUTIVE_LEADER_SLOTS / GRACE_TICKS_FACTOR,
                 ),
             ))
@@ -501,7 +507,11 @@ impl PohRecorder {
         }
 
         let (leader_first_tick_height_including_grace_ticks, leader_last_tick_height, grace_ticks) =
-            Self::compute_leader_slot_tick_heights(next_leader_slot, self.ticks_per_slot);
+            Self::compute_leader_slot_tick_heights(
+                next_leader_slot,
+                self.ticks_per_slot,
+                self.max_grace_slots,
+            );
         self.grace_ticks = grace_ticks;
         self.leader_first_tick_height_including_grace_ticks =
             leader_first_tick_height_including_grace_ticks;
@@ -847,6 +857,7 @@ impl PohRecorder {
         leader_schedule_cache: &Arc<LeaderScheduleCache>,
         poh_config: &PohConfig,
         poh_timing_point_sender: Option<PohTimingSender>,
+        max_grace_slots: u64,
         is_exited: Arc<AtomicBool>,
     ) -> (Self, Receiver<WorkingBankEntry>, Receiver<Record>) {
         let tick_number = 0;
@@ -863,7 +874,11 @@ impl PohRecorder {
         let (sender, receiver) = unbounded();
         let (record_sender, record_receiver) = unbounded();
         let (leader_first_tick_height_including_grace_ticks, leader_last_tick_height, grace_ticks) =
-            Self::compute_leader_slot_tick_heights(next_leader_slot, ticks_per_slot);
+            Self::compute_leader_slot_tick_heights(
+                next_leader_slot,
+                ticks_per_slot,
+                max_grace_slots,
+            );
         (
             Self {
                 poh,
@@ -878,6 +893,7 @@ impl PohRecorder {
                 leader_first_tick_height_including_grace_ticks,
                 leader_last_tick_height,
                 grace_ticks,
+                max_grace_slots,
                 id: *id,
                 blockstore: blockstore.clone(),
                 leader_schedule_cache: leader_schedule_cache.clone(),
@@ -929,6 +945,7 @@ impl PohRecorder {
             leader_schedule_cache,
             poh_config,
             None,
+            MAX_GRACE_SLOTS,
             is_exited,
         )
     }
@@ -1702,6 +1719,7 @@ mod tests {
                     &Arc::new(LeaderScheduleCache::default()),
                     &PohConfig::default(),
                     None,
+                    MAX_GRACE_SLOTS,
                     Arc::new(AtomicBool::default()),
                 );
             poh_recorder.set_bank(&bank, false);
@@ -2077,28 +2095,39 @@ mod tests {
     #[test]
     fn test_compute_leader_slot_tick_heights() {
         assert_eq!(
-            PohRecorder::compute_leader_slot_tick_heights(None, 0),
+            PohRecorder::compute_leader_slot_tick_heights(None, 0, MAX_GRACE_SLOTS),
             (None, 0, 0)
         );
 
         assert_eq!(
-            PohRecorder::compute_leader_slot_tick_heights(Some((4, 4)), 8),
+            PohRecorder::compute_leader_slot_tick_heights(Some((4, 4)), 8, MAX_GRACE_SLOTS),
             (Some(37), 40, 4)
         );
 
         assert_eq!(
-            PohRecorder::compute_leader_slot_tick_heights(Some((4, 7)), 8),
+            PohRecorder::compute_leader_slot_tick_heights(Some((4, 7)), 8, MAX_GRACE_SLOTS),
             (Some(49), 64, 2 * 8)
         );
 
         assert_eq!(
-            PohRecorder::compute_leader_slot_tick_heights(Some((6, 7)), 8),
+            PohRecorder::compute_leader_slot_tick_heights(Some((6, 7)), 8, MAX_GRACE_SLOTS),
             (Some(57), 64, 8)
         );
 
         assert_eq!(
-            PohRecorder::compute_leader_slot_tick_heights(Some((6, 7)), 4),
+            PohRecorder::compute_leader_slot_tick_heights(Some((6, 7)), 4, MAX_GRACE_SLOTS),
             (Some(29), 32, 4)
         );
+
+        // No grace period
+        assert_eq!(
+            PohRecorder::compute_leader_slot_tick_heights(Some((4, 7)), 8, 0),
+            (Some(33), 64, 0)
+        );
+
+        assert_eq!(
+            PohRecorder::compute_leader_slot_tick_heights(Some((4, 7)), 8, 1),
+            (Some(41), 64, 8)
+        );
     }
 }
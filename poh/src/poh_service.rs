@@ -168,23 +168,40 @@ impl PohService {
         poh_exit: &AtomicBool,
         record_receiver: Receiver<Record>,
     ) {
-        let mut last_tick = Instant::now();
+        let mut next_tick = Instant::now() + poh_config.target_tick_duration;
         while !poh_exit.load(Ordering::Relaxed) {
-            let remaining_tick_time = poh_config
-                .target_tick_duration
-                .saturating_sub(last_tick.elapsed());
+            let remaining_tick_time = next_tick.saturating_duration_since(Instant::now());
             Self::read_record_receiver_and_process(
                 &poh_recorder,
                 &record_receiver,
                 remaining_tick_time,
             );
             if remaining_tick_time.is_zero() {
-                last_tick = Instant::now();
+                next_tick = Self::next_tick_deadline(
+                    next_tick,
+                    poh_config.target_tick_duration,
+                    Instant::now(),
+                );
                 poh_recorder.write().unwrap().tick();
             }
         }
     }
 
+    /// Returns the deadline of the tick following the one due at `deadline`.
+    /// Deadlines advance by a fixed `tick_duration` so that time spent
+    /// overshooting one tick is made up on the next, rather than accumulating
+    /// into slot time drift. If the producer has stalled for more than a full
+    /// tick, the schedule restarts from `now` instead of bursting ticks to
+    /// catch up.
+    fn next_tick_deadline(deadline: Instant, tick_duration: Duration, now: Instant) -> Instant {
+        let next_deadline = deadline + tick_duration;
+        if next_deadline + tick_duration < now {
+            now + tick_duration
+        } else {
+            next_deadline
+        }
+    }
+
     pub fn read_record_receiver_and_process(
         poh_recorder: &Arc<RwLock<PohRecorder>>,
         record_receiver: &Receiver<Record>,
@@ -214,19 +231,21 @@ impl PohService {
     ) {
         let mut warned = false;
         let mut elapsed_ticks = 0;
-        let mut last_tick = Instant::now();
+        let mut next_tick = Instant::now() + poh_config.target_tick_duration;
         let num_ticks = poh_config.target_tick_count.unwrap();
         while elapsed_ticks < num_ticks {
-            let remaining_tick_time = poh_config
-                .target_tick_duration
-                .saturating_sub(last_tick.elapsed());
+            let remaining_tick_time = next_tick.saturating_duration_since(Instant::now());
             Self::read_record_receiver_and_process(
                 &poh_recorder,
                 &record_receiver,
                 Duration::from_millis(0),
             );
             if remaining_tick_time.is_zero() {
-                last_tick = Instant::now();
+                next_tick = Self::next_tick_deadline(
+                    next_tick,
+                    poh_config.target_tick_duration,
+                    Instant::now(),
+                );
                 poh_recorder.write().unwrap().tick();
                 elapsed_ticks += 1;
             }
@@ -399,6 +418,30 @@ mod tests {
         std::{thread::sleep, time::Duration},
     };
 
+    #[test]
+    fn test_next_tick_deadline() {
+        let tick = Duration::from_millis(10);
+        let start = Instant::now();
+
+        // Overshooting a tick is absorbed by the next deadline
+        let deadline = start + tick;
+        assert_eq!(
+            PohService::next_tick_deadline(deadline, tick, deadline + Duration::from_millis(3)),
+            start + 2 * tick
+        );
+        // Falling up to a tick behind still keeps the schedule
+        assert_eq!(
+            PohService::next_tick_deadline(deadline, tick, deadline + 2 * tick),
+            start + 2 * tick
+        );
+        // A longer stall restarts the schedule rather than bursting ticks
+        let now = deadline + 5 * tick;
+        assert_eq!(
+            PohService::next_tick_deadline(deadline, tick, now),
+            now + tick
+        );
+    }
+
     #[test]
     #[ignore]
     fn test_poh_service() {
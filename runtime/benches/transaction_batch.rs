@@ -0,0 +1,91 @@
+#![feature(test)]
+#![allow(clippy::integer_arithmetic)]
+
+extern crate test;
+
+use {
+    rayon::{prelude::*, ThreadPool, ThreadPoolBuilder},
+    solana_runtime::bank::Bank,
+    solana_sdk::{
+        account::AccountSharedData,
+        genesis_config::create_genesis_config,
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        system_program, system_transaction,
+        transaction::Transaction,
+    },
+    std::sync::Arc,
+    test::Bencher,
+};
+
+const NUM_TRANSACTIONS: usize = 4096;
+const ACCOUNT_LAMPORTS: u64 = 1_000_000_000_000;
+
+/// Creates a bank with `num_transactions` funded payer and recipient accounts
+/// and a non-conflicting transfer between each pair
+fn setup(num_transactions: usize) -> (Arc<Bank>, Vec<Transaction>) {
+    let (genesis_config, _mint_keypair) = create_genesis_config(ACCOUNT_LAMPORTS);
+    let bank = Arc::new(Bank::new_for_benches(&genesis_config));
+    let account = AccountSharedData::new(ACCOUNT_LAMPORTS, 0, &system_program::id());
+    let transactions = (0..num_transactions)
+        .map(|_| {
+            let from = Keypair::new();
+            let to = Pubkey::new_unique();
+            bank.store_account(&from.pubkey(), &account);
+            bank.store_account(&to, &account);
+            system_transaction::transfer(&from, &to, 1, bank.last_blockhash())
+        })
+        .collect();
+    (bank, transactions)
+}
+
+fn new_thread_pool(num_threads: usize) -> ThreadPool {
+    ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .thread_name(|i| format!("solBenchTx{i:02}"))
+        .build()
+        .unwrap()
+}
+
+fn process_batches(
+    bank: &Bank,
+    transactions: &[Transaction],
+    batch_size: usize,
+    thread_pool: &ThreadPool,
+) {
+    // Since transactions are processed repeatedly, clear their signatures.
+    bank.clear_signatures();
+    thread_pool.install(|| {
+        transactions.par_chunks(batch_size).for_each(|batch| {
+            let results = bank.process_transactions(batch.iter());
+            assert!(results.iter().all(Result::is_ok));
+        })
+    });
+}
+
+fn bench_transaction_batch(bencher: &mut Bencher, batch_size: usize, num_threads: usize) {
+    solana_logger::setup();
+    let (bank, transactions) = setup(NUM_TRANSACTIONS);
+    let thread_pool = new_thread_pool(num_threads);
+    bencher.iter(|| process_batches(&bank, &transactions, batch_size, &thread_pool));
+}
+
+#[bench]
+fn bench_transaction_batch_1_thread_batch_1(bencher: &mut Bencher) {
+    bench_transaction_batch(bencher, 1, 1);
+}
+
+#[bench]
+fn bench_transaction_batch_1_thread_batch_64(bencher: &mut Bencher) {
+    bench_transaction_batch(bencher, 64, 1);
+}
+
+#[bench]
+fn bench_transaction_batch_4_threads_batch_1(bencher: &mut Bencher) {
+    bench_transaction_batch(bencher, 1, 4);
+}
+
+#[bench]
+fn bench_transaction_batch_4_threads_batch_64(bencher: &mut Bencher) {
+    bench_transaction_batch(bencher, 64, 4);
+}
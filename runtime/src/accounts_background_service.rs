@@ -334,9 +334,19 @@ impl SnapshotRequestHandler {
                     },
                 )
                 .unwrap();
-            assert_eq!(previous_accounts_hash, this_accounts_hash);
+            if previous_accounts_hash != this_accounts_hash {
+                error!(
+                    "accounts hash mismatch at slot {}: index: {}, storages: {}",
+                    snapshot_root_bank.slot(),
+                    previous_accounts_hash.0,
+                    this_accounts_hash.0,
+                );
+            }
             assert_eq!(capitalization, snapshot_root_bank.capitalization());
-            this_accounts_hash
+            // Send the index-derived hash along with the accounts package, so that
+            // AccountsHashVerifier checks it against the package's storages and
+            // either panics or halts the validator on a mismatch.
+            previous_accounts_hash
         });
 
         let mut clean_time = Measure::start("clean_time");
@@ -144,6 +144,13 @@ impl VoteStateUpdate {
         }
     }
 
+    /// Builds an update that carries the full tower of `vote_state`, i.e. all of
+    /// its lockouts and its root, so it can replace the on-chain state in a
+    /// single instruction.
+    pub fn from_vote_state(vote_state: &VoteState, hash: Hash) -> Self {
+        Self::new(vote_state.votes.clone(), vote_state.root_slot, hash)
+    }
+
     pub fn slots(&self) -> Vec<Slot> {
         self.lockouts.iter().map(|lockout| lockout.slot()).collect()
     }
@@ -711,6 +718,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_vote_state_update_from_vote_state() {
+        let mut vote_state = VoteState::default();
+        for slot in 1..=MAX_LOCKOUT_HISTORY as Slot + 2 {
+            vote_state.process_next_vote_slot(slot, 0);
+        }
+        let hash = Hash::new_unique();
+        let vote_state_update = VoteStateUpdate::from_vote_state(&vote_state, hash);
+        assert_eq!(vote_state_update.lockouts, vote_state.votes);
+        assert_eq!(vote_state_update.root, vote_state.root_slot);
+        assert!(vote_state_update.root.is_some());
+        assert_eq!(vote_state_update.hash, hash);
+        assert_eq!(vote_state_update.timestamp, None);
+        assert_eq!(
+            vote_state_update.last_voted_slot(),
+            vote_state.last_voted_slot()
+        );
+    }
+
     #[test]
     fn test_vote_state_commission_split() {
         let vote_state = VoteState::default();
@@ -1072,6 +1072,37 @@ impl Transaction {
             .iter()
             .all(|signature| *signature != Signature::default())
     }
+
+    /// Returns the account that pays the transaction fee.
+    ///
+    /// The fee payer is always the first account in [`account_keys`]. It may
+    /// differ from the signers required by the transaction's instructions, as
+    /// when a relayer pays fees on behalf of another signer. Returns `None` if
+    /// the message has no accounts.
+    ///
+    /// [`account_keys`]: Message::account_keys
+    pub fn fee_payer(&self) -> Option<&Pubkey> {
+        self.message.account_keys.first()
+    }
+
+    /// Returns the pubkeys of required signers that have not yet signed.
+    ///
+    /// Signatures may be added in any order with [`Transaction::try_partial_sign`];
+    /// this reports which of the [`num_required_signatures`] leading
+    /// [`account_keys`] still hold the default signature.
+    ///
+    /// [`num_required_signatures`]: crate::message::MessageHeader::num_required_signatures
+    /// [`account_keys`]: Message::account_keys
+    pub fn get_unsigned_keys(&self) -> Vec<&Pubkey> {
+        self.message
+            .account_keys
+            .iter()
+            .zip(self.signatures.iter())
+            .take(self.message.header.num_required_signatures as usize)
+            .filter(|(_, signature)| **signature == Signature::default())
+            .map(|(pubkey, _)| pubkey)
+            .collect()
+    }
 }
 
 pub fn uses_durable_nonce(tx: &Transaction) -> Option<&CompiledInstruction> {
@@ -1419,6 +1450,51 @@ mod tests {
         assert!(tx.is_signed());
     }
 
+    #[test]
+    fn test_partial_sign_separate_fee_payer() {
+        let fee_payer = Keypair::new();
+        let signer0 = Keypair::new();
+        let signer1 = Keypair::new();
+        let ix = Instruction::new_with_bincode(
+            Pubkey::default(),
+            &0,
+            vec![
+                AccountMeta::new(signer0.pubkey(), true),
+                AccountMeta::new_readonly(signer1.pubkey(), true),
+            ],
+        );
+        let message = Message::new(&[ix], Some(&fee_payer.pubkey()));
+        let mut tx = Transaction::new_unsigned(message);
+        assert_eq!(tx.fee_payer(), Some(&fee_payer.pubkey()));
+        assert_eq!(
+            tx.get_unsigned_keys(),
+            vec![&fee_payer.pubkey(), &signer0.pubkey(), &signer1.pubkey()]
+        );
+
+        // Instruction signers sign first, in any order, then the fee payer
+        tx.try_partial_sign(&[&signer1], Hash::default()).unwrap();
+        assert_eq!(
+            tx.get_unsigned_keys(),
+            vec![&fee_payer.pubkey(), &signer0.pubkey()]
+        );
+        tx.try_partial_sign(&[&signer0], Hash::default()).unwrap();
+        assert_eq!(tx.get_unsigned_keys(), vec![&fee_payer.pubkey()]);
+        assert!(!tx.is_signed());
+        tx.try_partial_sign(&[&fee_payer], Hash::default()).unwrap();
+        assert!(tx.get_unsigned_keys().is_empty());
+        assert!(tx.is_signed());
+        assert_eq!(tx.verify(), Ok(()));
+
+        assert_eq!(
+            tx.try_partial_sign(&[&Keypair::new()], Hash::default()),
+            Err(SignerError::KeypairPubkeyMismatch)
+        );
+        assert_eq!(
+            Transaction::new_unsigned(Message::default()).fee_payer(),
+            None
+        );
+    }
+
     #[test]
     #[should_panic]
     fn test_transaction_missing_keypair() {
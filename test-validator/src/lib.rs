@@ -14,7 +14,8 @@ use {
         socketaddr,
     },
     solana_ledger::{
-        blockstore::create_new_ledger, blockstore_options::LedgerColumnOptions,
+        blockstore::{create_new_ledger, Blockstore},
+        blockstore_options::LedgerColumnOptions,
         create_new_tmp_ledger,
     },
     solana_net_utils::PortRange,
@@ -975,6 +976,10 @@ impl TestValidator {
         self.validator.as_ref().unwrap().bank_forks.clone()
     }
 
+    pub fn blockstore(&self) -> Arc<Blockstore> {
+        self.validator.as_ref().unwrap().blockstore.clone()
+    }
+
     pub fn repair_whitelist(&self) -> Arc<RwLock<HashSet<Pubkey>>> {
         Arc::new(RwLock::new(HashSet::default()))
     }
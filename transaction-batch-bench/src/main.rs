@@ -0,0 +1,140 @@
+#![allow(clippy::integer_arithmetic)]
+use {
+    clap::{crate_description, crate_name, value_t, values_t, App, Arg},
+    rayon::{prelude::*, ThreadPool, ThreadPoolBuilder},
+    solana_measure::measure::Measure,
+    solana_runtime::bank::Bank,
+    solana_sdk::{
+        account::AccountSharedData,
+        genesis_config::create_genesis_config,
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+        system_program, system_transaction,
+        transaction::Transaction,
+    },
+    std::sync::Arc,
+};
+
+const ACCOUNT_LAMPORTS: u64 = 1_000_000_000_000;
+
+/// Creates a bank with `num_transactions` funded payer and recipient accounts
+/// and a non-conflicting transfer between each pair
+fn setup(num_transactions: usize) -> (Arc<Bank>, Vec<Transaction>) {
+    let (genesis_config, _mint_keypair) = create_genesis_config(ACCOUNT_LAMPORTS);
+    let bank = Arc::new(Bank::new_for_benches(&genesis_config));
+    let account = AccountSharedData::new(ACCOUNT_LAMPORTS, 0, &system_program::id());
+    let transactions = (0..num_transactions)
+        .map(|_| {
+            let from = Keypair::new();
+            let to = Pubkey::new_unique();
+            bank.store_account(&from.pubkey(), &account);
+            bank.store_account(&to, &account);
+            system_transaction::transfer(&from, &to, 1, bank.last_blockhash())
+        })
+        .collect();
+    (bank, transactions)
+}
+
+fn new_thread_pool(num_threads: usize) -> ThreadPool {
+    ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .thread_name(|i| format!("solBenchTx{i:02}"))
+        .build()
+        .unwrap()
+}
+
+fn process_batches(
+    bank: &Bank,
+    transactions: &[Transaction],
+    batch_size: usize,
+    thread_pool: &ThreadPool,
+) {
+    // Since transactions are processed repeatedly, clear their signatures.
+    bank.clear_signatures();
+    thread_pool.install(|| {
+        transactions.par_chunks(batch_size).for_each(|batch| {
+            let results = bank.process_transactions(batch.iter());
+            assert!(results.iter().all(Result::is_ok));
+        })
+    });
+}
+
+fn main() {
+    solana_logger::setup();
+
+    let matches = App::new(crate_name!())
+        .about(crate_description!())
+        .version(solana_version::version!())
+        .arg(
+            Arg::with_name("batch_sizes")
+                .long("batch-sizes")
+                .takes_value(true)
+                .value_name("SIZES")
+                .use_delimiter(true)
+                .default_value("1,16,64,128,256")
+                .help("Comma separated transaction batch sizes to measure"),
+        )
+        .arg(
+            Arg::with_name("thread_counts")
+                .long("thread-counts")
+                .takes_value(true)
+                .value_name("COUNTS")
+                .use_delimiter(true)
+                .default_value("1,2,4,8")
+                .help("Comma separated thread counts to measure"),
+        )
+        .arg(
+            Arg::with_name("num_transactions")
+                .long("num-transactions")
+                .takes_value(true)
+                .value_name("NUM")
+                .default_value("4096")
+                .help("Number of non-conflicting transfers processed per iteration"),
+        )
+        .arg(
+            Arg::with_name("iterations")
+                .long("iterations")
+                .takes_value(true)
+                .value_name("ITERATIONS")
+                .default_value("10")
+                .help("Number of measured iterations per batch size and thread count"),
+        )
+        .get_matches();
+
+    let batch_sizes = values_t!(matches, "batch_sizes", usize).unwrap_or_else(|e| e.exit());
+    let thread_counts = values_t!(matches, "thread_counts", usize).unwrap_or_else(|e| e.exit());
+    let num_transactions =
+        value_t!(matches, "num_transactions", usize).unwrap_or_else(|e| e.exit());
+    let iterations = value_t!(matches, "iterations", usize).unwrap_or_else(|e| e.exit());
+    if batch_sizes.contains(&0) || thread_counts.contains(&0) {
+        eprintln!("error: batch sizes and thread counts must be greater than 0");
+        std::process::exit(1);
+    }
+
+    println!("Creating {num_transactions} transfers");
+    let (bank, transactions) = setup(num_transactions);
+
+    println!("Transactions per second, over {iterations} iterations:");
+    print!("{:>14}", "batch\\threads");
+    for num_threads in &thread_counts {
+        print!(" {num_threads:>10}");
+    }
+    println!();
+    for batch_size in &batch_sizes {
+        print!("{batch_size:>14}");
+        for num_threads in &thread_counts {
+            let thread_pool = new_thread_pool(*num_threads);
+            // Warm up the thread pool and the accounts cache
+            process_batches(&bank, &transactions, *batch_size, &thread_pool);
+
+            let mut measure = Measure::start("process_batches");
+            for _ in 0..iterations {
+                process_batches(&bank, &transactions, *batch_size, &thread_pool);
+            }
+            measure.stop();
+            let tps = (transactions.len() * iterations) as u64 * 1_000_000 / measure.as_us().max(1);
+            print!(" {tps:>10}");
+        }
+        println!();
+    }
+}
@@ -12,10 +12,12 @@ use {
     solana_gossip::{
         cluster_info::ClusterInfo, legacy_contact_info::LegacyContactInfo as ContactInfo,
     },
+    solana_ledger::{blockstore::Blockstore, blockstore_meta::SlotMeta},
     solana_rpc::rpc::verify_pubkey,
     solana_rpc_client_api::{config::RpcAccountIndex, custom_error::RpcCustomError},
     solana_runtime::{accounts_index::AccountIndex, bank_forks::BankForks},
     solana_sdk::{
+        clock::Slot,
         exit::Exit,
         pubkey::Pubkey,
         signature::{read_keypair_file, Keypair, Signer},
@@ -36,6 +38,7 @@ use {
 pub struct AdminRpcRequestMetadataPostInit {
     pub cluster_info: Arc<ClusterInfo>,
     pub bank_forks: Arc<RwLock<BankForks>>,
+    pub blockstore: Arc<Blockstore>,
     pub vote_account: Pubkey,
     pub repair_whitelist: Arc<RwLock<HashSet<Pubkey>>>,
 }
@@ -90,6 +93,35 @@ pub struct AdminRpcRepairWhitelist {
     pub whitelist: Vec<Pubkey>,
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AdminRpcSlotMeta {
+    pub slot: Slot,
+    pub parent_slot: Option<Slot>,
+    pub received: u64,
+    pub consumed: u64,
+    pub last_index: Option<u64>,
+    pub first_shred_timestamp: u64,
+    pub is_connected: bool,
+    pub is_full: bool,
+    pub next_slots: Vec<Slot>,
+}
+
+impl From<SlotMeta> for AdminRpcSlotMeta {
+    fn from(slot_meta: SlotMeta) -> Self {
+        Self {
+            slot: slot_meta.slot,
+            parent_slot: slot_meta.parent_slot,
+            received: slot_meta.received,
+            consumed: slot_meta.consumed,
+            last_index: slot_meta.last_index,
+            first_shred_timestamp: slot_meta.first_shred_timestamp,
+            is_connected: slot_meta.is_connected(),
+            is_full: slot_meta.is_full(),
+            next_slots: slot_meta.next_slots,
+        }
+    }
+}
+
 impl From<ContactInfo> for AdminRpcContactInfo {
     fn from(contact_info: ContactInfo) -> Self {
         let ContactInfo {
@@ -143,6 +175,23 @@ impl Display for AdminRpcContactInfo {
     }
 }
 
+impl Display for AdminRpcSlotMeta {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let display_option = |value: Option<u64>| {
+            value.map_or_else(|| "unknown".to_string(), |value| value.to_string())
+        };
+        writeln!(f, "Slot: {}", self.slot)?;
+        writeln!(f, "Parent Slot: {}", display_option(self.parent_slot))?;
+        writeln!(f, "Received: {}", self.received)?;
+        writeln!(f, "Consumed: {}", self.consumed)?;
+        writeln!(f, "Last Index: {}", display_option(self.last_index))?;
+        writeln!(f, "First Shred Timestamp: {}", self.first_shred_timestamp)?;
+        writeln!(f, "Connected: {}", self.is_connected)?;
+        writeln!(f, "Full: {}", self.is_full)?;
+        writeln!(f, "Next Slots: {:?}", self.next_slots)
+    }
+}
+
 impl Display for AdminRpcRepairWhitelist {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         writeln!(f, "Repair whitelist: {:?}", &self.whitelist)
@@ -206,6 +255,9 @@ pub trait AdminRpc {
     #[rpc(meta, name = "setRepairWhitelist")]
     fn set_repair_whitelist(&self, meta: Self::Metadata, whitelist: Vec<Pubkey>) -> Result<()>;
 
+    #[rpc(meta, name = "slotMeta")]
+    fn slot_meta(&self, meta: Self::Metadata, slot: Slot) -> Result<Option<AdminRpcSlotMeta>>;
+
     #[rpc(meta, name = "getSecondaryIndexKeySize")]
     fn get_secondary_index_key_size(
         &self,
@@ -380,6 +432,22 @@ impl AdminRpc for AdminRpcImpl {
         })
     }
 
+    fn slot_meta(&self, meta: Self::Metadata, slot: Slot) -> Result<Option<AdminRpcSlotMeta>> {
+        debug!("slot_meta request received for slot {}", slot);
+
+        meta.with_post_init(|post_init| {
+            post_init
+                .blockstore
+                .meta(slot)
+                .map(|slot_meta| slot_meta.map(AdminRpcSlotMeta::from))
+                .map_err(|err| jsonrpc_core::error::Error {
+                    code: jsonrpc_core::error::ErrorCode::InternalError,
+                    message: format!("Failed to read slot meta for slot {slot}: {err}"),
+                    data: None,
+                })
+        })
+    }
+
     fn get_secondary_index_key_size(
         &self,
         meta: Self::Metadata,
@@ -608,7 +676,11 @@ mod tests {
         serde_json::Value,
         solana_account_decoder::parse_token::spl_token_pubkey,
         solana_core::tower_storage::NullTowerStorage,
-        solana_ledger::genesis_utils::{create_genesis_config, GenesisConfigInfo},
+        solana_ledger::{
+            blockstore::make_slot_entries,
+            genesis_utils::{create_genesis_config, GenesisConfigInfo},
+            get_tmp_ledger_path_auto_delete,
+        },
         solana_rpc::rpc::create_validator_exit,
         solana_runtime::{
             accounts_index::AccountSecondaryIndexes,
@@ -625,6 +697,7 @@ mod tests {
             state::{Account as TokenAccount, AccountState as TokenAccountState, Mint},
         },
         std::{collections::HashSet, sync::atomic::AtomicBool},
+        tempfile::TempDir,
     };
 
     #[derive(Default)]
@@ -636,6 +709,8 @@ mod tests {
         io: MetaIoHandler<AdminRpcRequestMetadata>,
         meta: AdminRpcRequestMetadata,
         bank_forks: Arc<RwLock<BankForks>>,
+        blockstore: Arc<Blockstore>,
+        _ledger_path: TempDir,
     }
 
     impl RpcHandler {
@@ -659,6 +734,8 @@ mod tests {
                 secondary_indexes: config.account_indexes,
             });
             let vote_account = vote_keypair.pubkey();
+            let ledger_path = get_tmp_ledger_path_auto_delete!();
+            let blockstore = Arc::new(Blockstore::open(ledger_path.path()).unwrap());
             let start_progress = Arc::new(RwLock::new(ValidatorStartProgress::default()));
             let repair_whitelist = Arc::new(RwLock::new(HashSet::new()));
             let meta = AdminRpcRequestMetadata {
@@ -671,6 +748,7 @@ mod tests {
                 post_init: Arc::new(RwLock::new(Some(AdminRpcRequestMetadataPostInit {
                     cluster_info,
                     bank_forks: bank_forks.clone(),
+                    blockstore: blockstore.clone(),
                     vote_account,
                     repair_whitelist,
                 }))),
@@ -683,6 +761,8 @@ mod tests {
                 io,
                 meta,
                 bank_forks,
+                blockstore,
+                _ledger_path: ledger_path,
             }
         }
 
@@ -707,6 +787,54 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_slot_meta() {
+        let rpc = RpcHandler::start_with_config(TestConfig::default());
+        let RpcHandler {
+            io,
+            meta,
+            blockstore,
+            ..
+        } = &rpc;
+
+        // Unknown slot
+        let req = r#"{"jsonrpc":"2.0","id":1,"method":"slotMeta","params":[5]}"#;
+        let res = io.handle_request_sync(req, meta.clone());
+        let result: Value = serde_json::from_str(&res.expect("actual response"))
+            .expect("actual response deserialization");
+        assert_eq!(result["result"], Value::Null);
+
+        // Insert all but the first shred of slot 5, leaving a hole
+        let (mut shreds, _) = make_slot_entries(5, 4, 200, /*merkle_variant:*/ true);
+        let num_shreds = shreds.len() as u64;
+        assert!(num_shreds > 1);
+        shreds.remove(0);
+        blockstore.insert_shreds(shreds, None, false).unwrap();
+
+        let res = io.handle_request_sync(req, meta.clone());
+        let result: Value = serde_json::from_str(&res.expect("actual response"))
+            .expect("actual response deserialization");
+        let slot_meta: AdminRpcSlotMeta = serde_json::from_value(result["result"].clone()).unwrap();
+        assert_eq!(slot_meta.slot, 5);
+        assert_eq!(slot_meta.parent_slot, Some(4));
+        assert_eq!(slot_meta.received, num_shreds);
+        assert_eq!(slot_meta.consumed, 0);
+        assert_eq!(slot_meta.last_index, Some(num_shreds - 1));
+        assert!(!slot_meta.is_full);
+        assert!(!slot_meta.is_connected);
+        assert!(slot_meta.next_slots.is_empty());
+
+        // Parent slot 4 only knows about its child
+        let req = r#"{"jsonrpc":"2.0","id":1,"method":"slotMeta","params":[4]}"#;
+        let res = io.handle_request_sync(req, meta.clone());
+        let result: Value = serde_json::from_str(&res.expect("actual response"))
+            .expect("actual response deserialization");
+        let slot_meta: AdminRpcSlotMeta = serde_json::from_value(result["result"].clone()).unwrap();
+        assert_eq!(slot_meta.parent_slot, None);
+        assert_eq!(slot_meta.received, 0);
+        assert_eq!(slot_meta.next_slots, vec![5]);
+    }
+
     #[test]
     fn test_secondary_index_key_sizes() {
         for secondary_index_enabled in [true, false] {
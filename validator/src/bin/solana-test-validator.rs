@@ -496,6 +496,7 @@ fn main() {
             *admin_service_post_init.write().unwrap() =
                 Some(admin_rpc_service::AdminRpcRequestMetadataPostInit {
                     bank_forks: test_validator.bank_forks(),
+                    blockstore: test_validator.blockstore(),
                     cluster_info: test_validator.cluster_info(),
                     vote_account: test_validator.vote_account_address(),
                     repair_whitelist: test_validator.repair_whitelist(),
@@ -12,6 +12,7 @@ use {
     },
     solana_faucet::faucet::FAUCET_PORT,
     solana_net_utils::{MINIMUM_VALIDATOR_PORT_RANGE_WIDTH, VALIDATOR_PORT_RANGE},
+    solana_poh::poh_recorder::MAX_GRACE_SLOTS,
     solana_rpc::{rpc::MAX_REQUEST_BODY_SIZE, rpc_pubsub_service::PubSubConfig},
     solana_rpc_client_api::request::MAX_MULTIPLE_ACCOUNTS,
     solana_runtime::{
@@ -726,6 +727,17 @@ pub fn app<'a>(version: &'a str, default_args: &'a DefaultArgs) -> App<'a, 'a> {
                        from validators in the list will be prioritized over requests from other validators. \
                        [default: all validators]")
         )
+        .arg(
+            Arg::with_name("max_repair_response_bytes_per_second")
+                .hidden(true)
+                .long("max-repair-response-bytes-per-second")
+                .value_name("BYTES")
+                .takes_value(true)
+                .validator(is_parsable::<usize>)
+                .help("Maximum bandwidth, in bytes per second, used to serve repair responses \
+                       to other validators. Requests beyond this budget are dropped. \
+                       [default: 12000000]")
+        )
         .arg(
             Arg::with_name("gossip_validators")
                 .long("gossip-validator")
@@ -745,6 +757,17 @@ pub fn app<'a>(version: &'a str, default_args: &'a DefaultArgs) -> App<'a, 'a> {
                 .validator(is_parsable::<u64>)
                 .help("Milliseconds to wait in the TPU receiver for packet coalescing."),
         )
+        .arg(
+            Arg::with_name("tpu_dedup_reset_ms")
+                .long("tpu-dedup-reset-ms")
+                .value_name("MILLISECS")
+                .takes_value(true)
+                .validator(is_parsable::<u64>)
+                .hidden(true)
+                .help("Milliseconds after which the TPU's duplicate packet filter is reset. \
+                       A packet is dropped before signature verification if an identical \
+                       packet was received within this interval."),
+        )
         .arg(
             Arg::with_name("tpu_use_quic")
                 .long("tpu-use-quic")
@@ -1071,6 +1094,15 @@ pub fn app<'a>(version: &'a str, default_args: &'a DefaultArgs) -> App<'a, 'a> {
                 .takes_value(false)
                 .help("Abort the validator if a bank hash mismatch is detected within known validator set"),
         )
+        .arg(
+            Arg::with_name("halt_on_accounts_hash_mismatch")
+                .long("halt-on-accounts-hash-mismatch")
+                .requires("accounts_db_test_hash_calculation")
+                .takes_value(false)
+                .help("Exit the validator cleanly, instead of panicking, if the accounts hash \
+                       calculated from the accounts index by --accounts-db-test-hash-calculation \
+                       does not match the hash calculated from the snapshot storages"),
+        )
         .arg(
             Arg::with_name("snapshot_archive_format")
                 .long("snapshot-archive-format")
@@ -1135,6 +1167,26 @@ pub fn app<'a>(version: &'a str, default_args: &'a DefaultArgs) -> App<'a, 'a> {
                 .value_name("NUM")
                 .help("Specify hashes per batch in PoH service"),
         )
+        .arg(
+            Arg::with_name("max_leader_grace_slots")
+                .hidden(true)
+                .long("max-leader-grace-slots")
+                .takes_value(true)
+                .value_name("SLOTS")
+                .validator(|value| {
+                    let max_grace_slots = value.parse::<u64>().map_err(|err| err.to_string())?;
+                    if max_grace_slots > MAX_GRACE_SLOTS {
+                        Err(format!(
+                            "max leader grace slots cannot be larger than {MAX_GRACE_SLOTS}"
+                        ))
+                    } else {
+                        Ok(())
+                    }
+                })
+                .help("Maximum number of slots to wait for the previous leader's blocks \
+                       before starting a leader slot on top of the observed tip. \
+                       The grace period can only be shortened from the default"),
+        )
         .arg(
             Arg::with_name("process_ledger_before_services")
                 .long("process-ledger-before-services")
@@ -1425,6 +1477,29 @@ pub fn app<'a>(version: &'a str, default_args: &'a DefaultArgs) -> App<'a, 'a> {
                                     running validator instance")
                 )
         )
+        .subcommand(
+            SubCommand::with_name("slot-meta")
+                .about("Display the blockstore metadata of a slot, useful to diagnose \
+                        why a slot does not complete")
+                .setting(AppSettings::ArgRequiredElseHelp)
+                .arg(
+                    Arg::with_name("slot")
+                        .index(1)
+                        .value_name("SLOT")
+                        .takes_value(true)
+                        .required(true)
+                        .validator(is_slot)
+                        .help("Slot to display")
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .long("output")
+                        .takes_value(true)
+                        .value_name("MODE")
+                        .possible_values(&["json", "json-compact"])
+                        .help("Output display mode")
+                )
+        )
         .subcommand(
             SubCommand::with_name("init")
                 .about("Initialize the ledger directory then exit")
@@ -9,9 +9,10 @@ use {
     solana_clap_utils::input_parsers::{keypair_of, keypairs_of, pubkey_of, value_of},
     solana_core::{
         ledger_cleanup_service::{DEFAULT_MAX_LEDGER_SHREDS, DEFAULT_MIN_MAX_LEDGER_SHREDS},
+        serve_repair::DEFAULT_MAX_REPAIR_RESPONSE_BYTES_PER_SECOND,
         system_monitor_service::SystemMonitorService,
         tower_storage,
-        tpu::DEFAULT_TPU_COALESCE_MS,
+        tpu::{DEFAULT_TPU_COALESCE_MS, DEFAULT_TPU_DEDUP_RESET_MS},
         validator::{is_snapshot_config_valid, Validator, ValidatorConfig, ValidatorStartProgress},
     },
     solana_gossip::{cluster_info::Node, legacy_contact_info::LegacyContactInfo as ContactInfo},
@@ -19,7 +20,7 @@ use {
         BlockstoreCompressionType, BlockstoreRecoveryMode, LedgerColumnOptions, ShredStorageType,
     },
     solana_perf::recycler::enable_recycler_warming,
-    solana_poh::poh_service,
+    solana_poh::{poh_recorder::MAX_GRACE_SLOTS, poh_service},
     solana_rpc::{
         rpc::{JsonRpcConfig, RpcBigtableConfig},
         rpc_pubsub_service::PubSubConfig,
@@ -535,6 +536,31 @@ pub fn main() {
             }
             return;
         }
+        ("slot-meta", Some(subcommand_matches)) => {
+            let slot = value_t_or_exit!(subcommand_matches, "slot", Slot);
+            let output_mode = subcommand_matches.value_of("output");
+            let admin_client = admin_rpc_service::connect(&ledger_path);
+            let slot_meta = admin_rpc_service::runtime()
+                .block_on(async move { admin_client.await?.slot_meta(slot).await })
+                .unwrap_or_else(|err| {
+                    eprintln!("Slot meta query failed: {err}");
+                    exit(1);
+                });
+            let slot_meta = slot_meta.unwrap_or_else(|| {
+                eprintln!("No slot meta found for slot {slot}");
+                exit(1);
+            });
+            if let Some(mode) = output_mode {
+                match mode {
+                    "json" => println!("{}", serde_json::to_string_pretty(&slot_meta).unwrap()),
+                    "json-compact" => print!("{}", serde_json::to_string(&slot_meta).unwrap()),
+                    _ => unreachable!(),
+                }
+            } else {
+                print!("{slot_meta}");
+            }
+            return;
+        }
         ("init", _) => Operation::Initialize,
         ("exit", Some(subcommand_matches)) => {
             let min_idle_time = value_t_or_exit!(subcommand_matches, "min_idle_time", usize);
@@ -822,6 +848,8 @@ pub fn main() {
     let do_port_check = !matches.is_present("no_port_check");
     let tpu_coalesce_ms =
         value_t!(matches, "tpu_coalesce_ms", u64).unwrap_or(DEFAULT_TPU_COALESCE_MS);
+    let tpu_dedup_reset_ms =
+        value_t!(matches, "tpu_dedup_reset_ms", u64).unwrap_or(DEFAULT_TPU_DEDUP_RESET_MS);
     let wal_recovery_mode = matches
         .value_of("wal_recovery_mode")
         .map(BlockstoreRecoveryMode::from);
@@ -1170,6 +1198,12 @@ pub fn main() {
         known_validators,
         repair_validators,
         repair_whitelist: repair_whitelist.clone(),
+        max_repair_response_bytes_per_second: value_t!(
+            matches,
+            "max_repair_response_bytes_per_second",
+            usize
+        )
+        .unwrap_or(DEFAULT_MAX_REPAIR_RESPONSE_BYTES_PER_SECOND),
         gossip_validators,
         wal_recovery_mode,
         poh_verify: !matches.is_present("skip_poh_verify"),
@@ -1205,12 +1239,15 @@ pub fn main() {
             .unwrap_or(poh_service::DEFAULT_PINNED_CPU_CORE),
         poh_hashes_per_batch: value_of(&matches, "poh_hashes_per_batch")
             .unwrap_or(poh_service::DEFAULT_HASHES_PER_BATCH),
+        max_leader_grace_slots: value_of(&matches, "max_leader_grace_slots")
+            .unwrap_or(MAX_GRACE_SLOTS),
         process_ledger_before_services: matches.is_present("process_ledger_before_services"),
         account_indexes,
         accounts_db_test_hash_calculation: matches.is_present("accounts_db_test_hash_calculation"),
         accounts_db_config,
         accounts_db_skip_shrink: matches.is_present("accounts_db_skip_shrink"),
         tpu_coalesce_ms,
+        tpu_dedup_reset_ms,
         no_wait_for_vote_to_start_leader: matches.is_present("no_wait_for_vote_to_start_leader"),
         accounts_shrink_ratio,
         runtime_config: RuntimeConfig {
@@ -1451,6 +1488,9 @@ pub fn main() {
     if matches.is_present("halt_on_known_validators_accounts_hash_mismatch") {
         validator_config.halt_on_known_validators_accounts_hash_mismatch = true;
     }
+    if matches.is_present("halt_on_accounts_hash_mismatch") {
+        validator_config.halt_on_accounts_hash_mismatch = true;
+    }
 
     let public_rpc_addr = matches.value_of("public_rpc_addr").map(|addr| {
         solana_net_utils::parse_host_port(addr).unwrap_or_else(|e| {
@@ -1646,6 +1686,7 @@ pub fn main() {
     *admin_service_post_init.write().unwrap() =
         Some(admin_rpc_service::AdminRpcRequestMetadataPostInit {
             bank_forks: validator.bank_forks.clone(),
+            blockstore: validator.blockstore.clone(),
             cluster_info: validator.cluster_info.clone(),
             vote_account,
             repair_whitelist,